@@ -0,0 +1,36 @@
+/// Finds the median timing for `part` in the most recent divan run recorded
+/// in a `day-NN.bench.txt` file (as appended by `just bench`).
+pub fn latest_median(contents: &str, part: &str) -> Option<String> {
+    contents.lines().rev().find_map(|line| {
+        let line = line.trim_start_matches(['╰', '├', '─', ' ']);
+        let (name, _) = line.split_once(char::is_whitespace)?;
+        if name != part {
+            return None;
+        }
+        // columns are: `part fastest │ slowest │ median │ mean │ samples │ iters`
+        line.split('│')
+            .nth(2)
+            .map(|median| median.trim().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_median() {
+        let input = r#"day_06    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ part1  290.4 ns      │ 50.54 µs      │ 374.4 ns      │ 869.4 ns      │ 100     │ 100
+
+day_06    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ part2  366.6 ns      │ 377.1 ns      │ 371.8 ns      │ 371 ns        │ 100     │ 1600
+
+day_06    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ part1  290.4 ns      │ 17.33 µs      │ 380.1 ns      │ 529.1 ns      │ 100     │ 100
+"#;
+        assert_eq!(Some("380.1 ns".to_string()), latest_median(input, "part1"));
+        assert_eq!(Some("371.8 ns".to_string()), latest_median(input, "part2"));
+        assert_eq!(None, latest_median("", "part1"));
+    }
+}
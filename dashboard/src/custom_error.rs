@@ -0,0 +1,20 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum DashboardError {
+    #[error(transparent)]
+    #[diagnostic(code(dashboard::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error("Unknown argument `{0}`")]
+    #[diagnostic(
+        code(dashboard::unknown_argument),
+        help("supported flags are --root, --out-dir, --skip-tests and --record-answers")
+    )]
+    UnknownArgument(String),
+
+    #[error("Missing value for `{0}`")]
+    #[diagnostic(code(dashboard::missing_value))]
+    MissingValue(String),
+}
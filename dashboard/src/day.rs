@@ -0,0 +1,144 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{bench, custom_error::DashboardError, test_runner::TestStatus};
+
+pub const PARTS: [&str; 2] = ["part1", "part2"];
+
+#[derive(Debug)]
+pub struct Day {
+    pub name: String,
+    pub path: PathBuf,
+    pub parts: Vec<Part>,
+}
+
+#[derive(Debug)]
+pub struct Part {
+    pub name: &'static str,
+    pub implemented: bool,
+    pub tests: TestStatus,
+    pub bench_median: Option<String>,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    Earned,
+    Pending,
+    Missing,
+}
+
+impl Part {
+    pub fn star(&self) -> Star {
+        match (self.implemented, self.tests) {
+            (false, _) => Star::Missing,
+            (true, TestStatus::Passed) => Star::Earned,
+            (true, _) => Star::Pending,
+        }
+    }
+}
+
+/// Collects every `day-NN` crate under `root`, sorted by day.
+pub fn scan_workspace(root: &Path) -> Result<Vec<Day>, DashboardError> {
+    let mut days = fs::read_dir(root)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("Cargo.toml").is_file())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            is_day_crate(&name).then_some((name, entry.path()))
+        })
+        .map(|(name, path)| read_day(root, name, path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    days.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(days)
+}
+
+fn is_day_crate(name: &str) -> bool {
+    name.strip_prefix("day-")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn read_day(root: &Path, name: String, path: PathBuf) -> Result<Day, DashboardError> {
+    let bench_results =
+        fs::read_to_string(root.join(format!("{name}.bench.txt"))).unwrap_or_default();
+    let answers = fs::read_to_string(path.join("answers.txt")).unwrap_or_default();
+
+    let parts = PARTS
+        .iter()
+        .map(|part| {
+            let source = fs::read_to_string(path.join("src").join(format!("{part}.rs")))?;
+            Ok(Part {
+                name: part,
+                implemented: is_implemented(&source),
+                tests: TestStatus::NotRun,
+                bench_median: bench::latest_median(&bench_results, part),
+                answer: recorded_answer(&answers, part),
+            })
+        })
+        .collect::<Result<Vec<_>, DashboardError>>()?;
+
+    Ok(Day { name, path, parts })
+}
+
+/// A part counts as implemented once the non-test code no longer contains a `todo!()`.
+pub fn is_implemented(source: &str) -> bool {
+    let code = source
+        .split_once("#[cfg(test)]")
+        .map_or(source, |(code, _)| code);
+    !code.contains("todo!(")
+}
+
+/// Reads `partN: answer` lines from a day's `answers.txt`.
+pub fn recorded_answer(answers: &str, part: &str) -> Option<String> {
+    answers.lines().find_map(|line| {
+        let (name, answer) = line.split_once(':')?;
+        (name.trim() == part).then(|| answer.trim().to_string())
+    })
+}
+
+pub fn format_answers(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| Some(format!("{}: {}\n", part.name, part.answer.as_ref()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_implemented() {
+        let template = r#"pub fn process(_input: &str) -> miette::Result<String, AocError> {
+    todo!("day 01 - part 1");
+}
+
+#[cfg(test)]
+mod tests {}"#;
+        let solved = r#"pub fn process(input: &str) -> miette::Result<u32, AocError> {
+    Ok(input.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    fn test_process() {
+        todo!("haven't built test yet");
+    }
+}"#;
+        assert!(!is_implemented(template));
+        assert!(is_implemented(solved));
+    }
+
+    #[test]
+    fn test_recorded_answer() {
+        let answers = "part1: 142\npart2:281\n";
+        assert_eq!(Some("142".to_string()), recorded_answer(answers, "part1"));
+        assert_eq!(Some("281".to_string()), recorded_answer(answers, "part2"));
+        assert_eq!(None, recorded_answer("", "part1"));
+        assert!(is_day_crate("day-01"));
+        assert!(!is_day_crate("daily-template"));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bench;
+pub mod custom_error;
+pub mod day;
+pub mod render;
+pub mod test_runner;
@@ -0,0 +1,71 @@
+use std::{fs, path::PathBuf};
+
+use dashboard::{custom_error::DashboardError, day, render, test_runner};
+use miette::Context;
+
+struct Args {
+    root: PathBuf,
+    out_dir: PathBuf,
+    skip_tests: bool,
+    record_answers: bool,
+}
+
+fn parse_args() -> Result<Args, DashboardError> {
+    let root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let mut args = Args {
+        out_dir: root.clone(),
+        root,
+        skip_tests: false,
+        record_answers: false,
+    };
+
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "--root" => args.root = argv.next().ok_or(DashboardError::MissingValue(arg))?.into(),
+            "--out-dir" => {
+                args.out_dir = argv.next().ok_or(DashboardError::MissingValue(arg))?.into()
+            }
+            "--skip-tests" => args.skip_tests = true,
+            "--record-answers" => args.record_answers = true,
+            _ => return Err(DashboardError::UnknownArgument(arg)),
+        }
+    }
+    Ok(args)
+}
+
+fn main() -> miette::Result<()> {
+    let args = parse_args()?;
+
+    let mut days = day::scan_workspace(&args.root).context("scan workspace")?;
+    for d in days.iter_mut() {
+        for part in d.parts.iter_mut() {
+            if !args.skip_tests {
+                part.tests = test_runner::run_part_tests(&args.root, &d.name, part.name)
+                    .context("run tests")?;
+            }
+            if args.record_answers && part.implemented {
+                if let Some(answer) = test_runner::run_part_binary(&args.root, &d.name, part.name)
+                    .context("run binary")?
+                {
+                    part.answer = Some(answer);
+                }
+            }
+        }
+        if args.record_answers {
+            fs::write(d.path.join("answers.txt"), day::format_answers(&d.parts))
+                .map_err(DashboardError::from)
+                .context("write answers")?;
+        }
+    }
+
+    fs::create_dir_all(&args.out_dir).map_err(DashboardError::from)?;
+    let markdown_path = args.out_dir.join("PROGRESS.md");
+    let html_path = args.out_dir.join("progress.html");
+    fs::write(&markdown_path, render::markdown(&days)).map_err(DashboardError::from)?;
+    fs::write(&html_path, render::html(&days)).map_err(DashboardError::from)?;
+
+    println!("{}", markdown_path.display());
+    println!("{}", html_path.display());
+    Ok(())
+}
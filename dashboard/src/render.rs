@@ -0,0 +1,105 @@
+use crate::day::{Day, Star};
+
+fn star_symbol(star: Star) -> &'static str {
+    match star {
+        Star::Earned => "★",
+        Star::Pending => "☆",
+        Star::Missing => "·",
+    }
+}
+
+fn earned_stars(days: &[Day]) -> usize {
+    days.iter()
+        .flat_map(|d| &d.parts)
+        .filter(|p| p.star() == Star::Earned)
+        .count()
+}
+
+fn total_parts(days: &[Day]) -> usize {
+    days.iter().map(|d| d.parts.len()).sum()
+}
+
+pub fn markdown(days: &[Day]) -> String {
+    let mut out = String::from("# Advent of Code 2023 progress\n\n");
+    out.push_str(&format!(
+        "{} of {} stars earned. ★ earned, ☆ implemented but tests not passing, · `todo!()`.\n\n",
+        earned_stars(days),
+        total_parts(days)
+    ));
+    out.push_str("| Day | Part | Star | Tests | Median | Answer |\n");
+    out.push_str("| --- | --- | :---: | --- | ---: | --- |\n");
+    for day in days {
+        for part in &day.parts {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                day.name,
+                part.name,
+                star_symbol(part.star()),
+                part.tests.label(),
+                part.bench_median.as_deref().unwrap_or("-"),
+                part.answer
+                    .as_ref()
+                    .map_or("-".to_string(), |a| format!("`{a}`")),
+            ));
+        }
+    }
+    out
+}
+
+pub fn html(days: &[Day]) -> String {
+    let mut rows = String::new();
+    for day in days {
+        for part in &day.parts {
+            let class = match part.star() {
+                Star::Earned => "earned",
+                Star::Pending => "pending",
+                Star::Missing => "missing",
+            };
+            rows.push_str(&format!(
+                "      <tr class=\"{class}\"><td>{}</td><td>{}</td><td class=\"star\">{}</td><td>{}</td><td>{}</td><td><code>{}</code></td></tr>\n",
+                escape(&day.name),
+                part.name,
+                star_symbol(part.star()),
+                part.tests.label(),
+                escape(part.bench_median.as_deref().unwrap_or("-")),
+                escape(part.answer.as_deref().unwrap_or("-")),
+            ));
+        }
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>Advent of Code 2023 progress</title>
+    <style>
+      body {{ font-family: monospace; background: #0f0f23; color: #cccccc; }}
+      table {{ border-collapse: collapse; }}
+      td, th {{ padding: 0.2em 1em; text-align: left; }}
+      .star {{ text-align: center; }}
+      .earned .star {{ color: #ffff66; }}
+      .pending .star {{ color: #9999cc; }}
+      .missing {{ color: #666666; }}
+    </style>
+  </head>
+  <body>
+    <h1>Advent of Code 2023 progress</h1>
+    <p>{} of {} stars earned.</p>
+    <table>
+      <tr><th>Day</th><th>Part</th><th>Star</th><th>Tests</th><th>Median</th><th>Answer</th></tr>
+{rows}    </table>
+  </body>
+</html>
+"#,
+        earned_stars(days),
+        total_parts(days),
+    )
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,87 @@
+use std::{path::Path, process::Command};
+
+use crate::custom_error::DashboardError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    BuildFailed,
+    NoTests,
+    NotRun,
+}
+
+impl TestStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TestStatus::Passed => "passing",
+            TestStatus::Failed => "failing",
+            TestStatus::BuildFailed => "build failed",
+            TestStatus::NoTests => "no tests",
+            TestStatus::NotRun => "not run",
+        }
+    }
+}
+
+/// Runs the unit tests of a single part module, e.g. `cargo test -p day-01 --lib part2::`.
+pub fn run_part_tests(root: &Path, day: &str, part: &str) -> Result<TestStatus, DashboardError> {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args(["test", "-q", "-p", day, "--lib", &format!("{part}::")])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    Ok(parse_test_output(&stdout))
+}
+
+pub fn parse_test_output(stdout: &str) -> TestStatus {
+    let Some(summary) = stdout.lines().find(|l| l.starts_with("test result:")) else {
+        return TestStatus::BuildFailed;
+    };
+    if !summary.starts_with("test result: ok") {
+        TestStatus::Failed
+    } else if summary.contains(" 0 passed") {
+        TestStatus::NoTests
+    } else {
+        TestStatus::Passed
+    }
+}
+
+/// Runs a part's binary in release mode and returns what it printed.
+pub fn run_part_binary(
+    root: &Path,
+    day: &str,
+    part: &str,
+) -> Result<Option<String>, DashboardError> {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args(["run", "-q", "--release", "-p", day, "--bin", part])
+        .output()?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let answer = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!answer.is_empty()).then_some(answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_output() {
+        assert_eq!(
+            TestStatus::Passed,
+            parse_test_output("..\ntest result: ok. 2 passed; 0 failed; 0 ignored")
+        );
+        assert_eq!(
+            TestStatus::Failed,
+            parse_test_output("F\ntest result: FAILED. 0 passed; 1 failed; 0 ignored")
+        );
+        assert_eq!(
+            TestStatus::NoTests,
+            parse_test_output("\ntest result: ok. 0 passed; 0 failed; 0 ignored")
+        );
+        assert_eq!(TestStatus::BuildFailed, parse_test_output(""));
+    }
+}
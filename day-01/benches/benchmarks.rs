@@ -14,3 +14,13 @@ fn part1() {
 fn part2() {
     part2::process(divan::black_box(include_str!("../input2.txt",))).unwrap();
 }
+
+#[divan::bench]
+fn part1_scan() {
+    scan::process_part1(divan::black_box(include_str!("../input1.txt",))).unwrap();
+}
+
+#[divan::bench]
+fn part2_scan() {
+    scan::process_part2(divan::black_box(include_str!("../input2.txt",))).unwrap();
+}
@@ -0,0 +1,90 @@
+//! Command line flags shared by the part 1 and part 2 binaries.
+use crate::{custom_error::AocError, report::ReportFormat};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    /// `--report table|json` prints every line instead of the sum.
+    pub report: Option<ReportFormat>,
+    /// `--vocabulary` takes a built-in name or a file of `token = value` lines.
+    pub vocabulary: Option<String>,
+}
+
+impl Args {
+    /// Parses the arguments after the program name. Flags outside `accepted`
+    /// are rejected, as is a flag that is missing its value.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        accepted: &[&str],
+    ) -> miette::Result<Self, AocError> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if !accepted.contains(&arg.as_str()) {
+                return Err(AocError::ParseError(format!("unknown argument `{arg}`")));
+            }
+            let mut value = || {
+                args.next()
+                    .filter(|value| !value.starts_with("--"))
+                    .ok_or_else(|| AocError::ParseError(format!("`{arg}` needs a value")))
+            };
+            match arg.as_str() {
+                "--report" => parsed.report = Some(value()?.parse()?),
+                "--vocabulary" => parsed.vocabulary = Some(value()?),
+                _ => return Err(AocError::ParseError(format!("unknown argument `{arg}`"))),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str], accepted: &[&str]) -> miette::Result<Args, AocError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()), accepted)
+    }
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        assert_eq!(Args::default(), parse(&[], &["--report"])?);
+        assert_eq!(
+            Args {
+                report: Some(ReportFormat::Json),
+                vocabulary: Some("german".to_string()),
+            },
+            parse(
+                &["--vocabulary", "german", "--report", "json"],
+                &["--report", "--vocabulary"]
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_arguments() {
+        let accepted = ["--report", "--vocabulary"];
+        for (args, message) in [
+            (&["--verbose"][..], "unknown argument `--verbose`"),
+            (&["--report"], "`--report` needs a value"),
+            (
+                &["--vocabulary", "--report", "json"],
+                "`--vocabulary` needs a value",
+            ),
+            (
+                &["--report", "xml"],
+                "unknown report format `xml`, expected table or json",
+            ),
+        ] {
+            let Err(AocError::ParseError(error)) = parse(args, &accepted) else {
+                panic!("{args:?} should be rejected");
+            };
+            assert_eq!(message, error);
+        }
+        let Err(AocError::ParseError(error)) = parse(&["--vocabulary", "german"], &["--report"])
+        else {
+            panic!("part 1 takes no vocabulary");
+        };
+        assert_eq!("unknown argument `--vocabulary`", error);
+    }
+}
@@ -1,4 +1,4 @@
-use day_01::part1::process;
+use day_01::{args::Args, part1::process, report};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -14,7 +14,20 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input1.txt");
+
+    // `--report table` or `--report json` prints every line instead of the sum
+    let args = Args::parse(std::env::args().skip(1), &["--report"])?;
+    if let Some(format) = args.report {
+        print!(
+            "{}",
+            report::part1(file)
+                .render(format)
+                .context("report part 1")?
+        );
+        return Ok(());
+    }
+
     let result = process(file).context("process part 1")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use day_01::part2::process;
+use day_01::{args::Args, part2::process_with_vocabulary, report, vocabulary::Vocabulary};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -13,8 +13,27 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
+    // `--vocabulary` takes a built-in name (english, german, french, numerals)
+    // or a file of `token = value` lines. `--report table` or `--report json`
+    // prints every line instead of the sum.
+    let args = Args::parse(std::env::args().skip(1), &["--vocabulary", "--report"])?;
+    let vocabulary = match args.vocabulary {
+        Some(name) => match Vocabulary::by_name(&name) {
+            Some(vocabulary) => vocabulary,
+            None => Vocabulary::from_file(&name).context("load vocabulary")?,
+        },
+        None => Vocabulary::english(),
+    };
+
     let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+
+    if let Some(format) = args.report {
+        let report = report::part2(file, &vocabulary).context("report part 2")?;
+        print!("{}", report.render(format).context("report part 2")?);
+        return Ok(());
+    }
+
+    let result = process_with_vocabulary(file, &vocabulary).context("process part 2")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -9,4 +9,10 @@ pub enum AocError {
     #[error("{0}")]
     #[diagnostic(code(aoc::parser_error))]
     ParseError(String),
+    #[error("`{token}` stands for {value}, but calibration digits go from 0 to 9")]
+    #[diagnostic(code(aoc::not_a_digit))]
+    NotADigit { token: String, value: u32 },
+    #[error(transparent)]
+    #[diagnostic(code(aoc::json_error))]
+    JsonError(#[from] serde_json::Error),
 }
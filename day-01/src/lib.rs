@@ -1,3 +1,8 @@
+pub mod args;
 pub mod custom_error;
 pub mod part1;
 pub mod part2;
+pub mod report;
+pub mod scan;
+pub mod stream;
+pub mod vocabulary;
@@ -1,9 +1,9 @@
 use nom::{
-    character::{
-        complete::{alpha0, newline, one_of},
-    },
-    multi::{fold_many1, separated_list0},
-    sequence::delimited, IResult,
+    character::complete::{alpha0, one_of},
+    combinator::all_consuming,
+    multi::fold_many1,
+    sequence::delimited,
+    IResult,
 };
 
 use crate::custom_error::AocError;
@@ -18,13 +18,40 @@ pub fn process(input: &str) -> miette::Result<u32, AocError> {
     Ok(sum)
 }
 
+/// Blank lines are skipped. The first line that isn't letters and digits
+/// is reported with its line number and what went wrong.
 pub fn parse(input: &str) -> miette::Result<Vec<(u32, u32)>, AocError> {
-    let (_, res) = separated_list0(newline, parse_line)(input)
-        .map_err(|e| AocError::ParseError(e.to_string()))?;
-    Ok(res)
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            all_consuming(parse_line)(line)
+                .map(|(_, digits)| digits)
+                .map_err(|_| AocError::ParseError(format!("line {}: {}", i + 1, line_error(line))))
+        })
+        .collect()
 }
 
-fn parse_line(input: &str) -> IResult<&str, (u32, u32)> {
+/// Why `line` isn't a calibration line: lines are ASCII letters and digits
+/// with at least one digit.
+pub(crate) fn line_error(line: &str) -> String {
+    if !line.chars().any(|c| c.is_ascii_digit()) {
+        return "no digits found".to_string();
+    }
+    match line
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
+    {
+        Some((i, c)) => format!(
+            "unexpected `{c}` at column {}",
+            line[..i].chars().count() + 1
+        ),
+        None => "invalid line".to_string(),
+    }
+}
+
+pub fn parse_line(input: &str) -> IResult<&str, (u32, u32)> {
     let (input, res) = fold_many1(
         delimited(alpha0, one_of("0123456789"), alpha0),
         || (None, None),
@@ -36,14 +63,13 @@ fn parse_line(input: &str) -> IResult<&str, (u32, u32)> {
             }
         },
     )(input)?;
-    let first_digit = res.0.expect("Missing first digit");
-    Ok((
-        input,
-        (
-            res.0.expect("Missing first digit"),
-            res.1.unwrap_or(first_digit),
-        ),
-    ))
+    let Some(first_digit) = res.0 else {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Digit,
+        )));
+    };
+    Ok((input, (first_digit, res.1.unwrap_or(first_digit))))
 }
 
 #[cfg(test)]
@@ -59,4 +85,23 @@ treb7uchet"#;
         assert_eq!(142, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_line_without_digits() {
+        let input = "1abc2\nabc\ntreb7uchet";
+        assert_eq!(
+            "line 2: no digits found",
+            process(input).unwrap_err().to_string()
+        );
+        let input = "1abc2\nab-c1";
+        assert_eq!(
+            "line 2: unexpected `-` at column 3",
+            process(input).unwrap_err().to_string()
+        );
+        let input = "1abc2\n\ntreb7uc-het";
+        assert_eq!(
+            "line 3: unexpected `-` at column 8",
+            process(input).unwrap_err().to_string()
+        );
+    }
 }
@@ -0,0 +1,193 @@
+//! Per-line breakdown of the calibration document. Bad lines are recorded
+//! with an error and skipped instead of aborting the whole sum.
+use std::{fmt::Write, str::FromStr};
+
+use nom::combinator::all_consuming;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    custom_error::AocError,
+    part1,
+    vocabulary::{Extractor, Vocabulary},
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum LineError {
+    #[error("no digits found")]
+    NoDigits,
+    #[error("{0}")]
+    Invalid(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LineReport {
+    pub line_number: usize,
+    pub text: String,
+    pub digits: Option<(u32, u32)>,
+    pub value: Option<u32>,
+    pub error: Option<LineError>,
+}
+
+/// How the binaries print a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Table,
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(ReportFormat::Table),
+            "json" => Ok(ReportFormat::Json),
+            _ => Err(AocError::ParseError(format!(
+                "unknown report format `{s}`, expected table or json"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CalibrationReport {
+    pub lines: Vec<LineReport>,
+    pub total: u32,
+    pub num_errors: usize,
+}
+
+/// Report using only numerals, as in part 1.
+pub fn part1(input: &str) -> CalibrationReport {
+    build(input, |line| {
+        all_consuming(part1::parse_line)(line)
+            .map(|(_, digits)| digits)
+            .map_err(|_| {
+                if line.chars().any(|c| c.is_ascii_digit()) {
+                    LineError::Invalid(part1::line_error(line))
+                } else {
+                    LineError::NoDigits
+                }
+            })
+    })
+}
+
+/// Report counting spelled-out digits from `vocabulary`, as in part 2.
+pub fn part2(input: &str, vocabulary: &Vocabulary) -> miette::Result<CalibrationReport, AocError> {
+    let extractor: Extractor = vocabulary.extractor()?;
+    Ok(build(input, |line| {
+        extractor.first_and_last(line).ok_or(LineError::NoDigits)
+    }))
+}
+
+fn build(
+    input: &str,
+    first_and_last: impl Fn(&str) -> Result<(u32, u32), LineError>,
+) -> CalibrationReport {
+    let lines: Vec<LineReport> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let result = first_and_last(line);
+            LineReport {
+                line_number: i + 1,
+                text: line.to_string(),
+                digits: result.as_ref().ok().copied(),
+                value: result.as_ref().ok().map(|(tens, ones)| 10 * tens + ones),
+                error: result.err(),
+            }
+        })
+        .collect();
+
+    CalibrationReport {
+        total: lines.iter().filter_map(|l| l.value).sum(),
+        num_errors: lines.iter().filter(|l| l.error.is_some()).count(),
+        lines,
+    }
+}
+
+impl CalibrationReport {
+    pub fn to_table(&self) -> String {
+        let text_width = self
+            .lines
+            .iter()
+            .map(|l| l.text.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let mut table = format!(
+            "{:>6}  {:<text_width$}  {:>6}  {:>5}  error\n",
+            "line", "text", "digits", "value"
+        );
+        for line in &self.lines {
+            let digits = line
+                .digits
+                .map_or("-".to_string(), |(tens, ones)| format!("{tens},{ones}"));
+            let value = line.value.map_or("-".to_string(), |v| v.to_string());
+            let error = line.error.as_ref().map_or(String::new(), |e| e.to_string());
+            let _ = writeln!(
+                table,
+                "{:>6}  {:<text_width$}  {:>6}  {:>5}  {}",
+                line.line_number, line.text, digits, value, error
+            );
+        }
+        let _ = writeln!(
+            table,
+            "total {} ({} lines, {} errors)",
+            self.total,
+            self.lines.len(),
+            self.num_errors
+        );
+        table
+    }
+
+    pub fn to_json(&self) -> miette::Result<String, AocError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn render(&self, format: ReportFormat) -> miette::Result<String, AocError> {
+        match format {
+            ReportFormat::Table => Ok(self.to_table()),
+            ReportFormat::Json => Ok(self.to_json()? + "\n"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_continues_past_bad_lines() -> miette::Result<()> {
+        let input = "1abc2\nnothing here\ntreb7uchet\n\nxtwone3four";
+
+        let report = part1(input);
+        assert_eq!(12 + 77 + 33, report.total);
+        assert_eq!(1, report.num_errors);
+        assert_eq!(Some(LineError::NoDigits), report.lines[1].error);
+        assert_eq!(5, report.lines[3].line_number);
+
+        let report = part2(input, &Vocabulary::english())?;
+        assert_eq!(12 + 77 + 24, report.total);
+        assert_eq!(
+            Some((2, 4)),
+            report.lines.last().and_then(|line| line.digits)
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()?).unwrap();
+        assert_eq!("no_digits", json["lines"][1]["error"]["kind"]);
+        assert!(json["lines"][0]["error"].is_null());
+        let invalid: serde_json::Value = serde_json::from_str(&part1("1a-b").to_json()?).unwrap();
+        assert_eq!("invalid", invalid["lines"][0]["error"]["kind"]);
+        assert_eq!(
+            "unexpected `-` at column 3",
+            invalid["lines"][0]["error"]["message"]
+        );
+        assert!(report
+            .to_table()
+            .ends_with("total 113 (4 lines, 1 errors)\n"));
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+//! Solver mode that looks for the first digit from the front of each line and
+//! the last digit from the back, instead of folding over every character.
+//! Numerals are found eight bytes at a time by classifying the bytes of a
+//! `u64` word (SWAR), so lines are mostly skipped over rather than parsed.
+use crate::{custom_error::AocError, vocabulary::Vocabulary};
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Sets the high bit of every byte of `word` that is an ASCII digit.
+fn digit_mask(word: u64) -> u64 {
+    // a digit has a high nibble of 3 and a low nibble below 10
+    let high_nibble = (word & (0xF0 * ONES)) ^ (0x30 * ONES);
+    let low_nibble_too_big = ((word & (0x0F * ONES)) + 0x06 * ONES) & (0x10 * ONES);
+    let not_digit = high_nibble | low_nibble_too_big;
+    // exact zero byte test, no borrows between bytes
+    !(((not_digit & !HIGH_BITS) + !HIGH_BITS) | not_digit) & HIGH_BITS
+}
+
+fn word_at(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().expect("chunk of 8 bytes"))
+}
+
+/// Index of the first ASCII digit in `line`.
+pub fn first_digit(line: &[u8]) -> Option<usize> {
+    let mut chunks = line.chunks_exact(8);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        let mask = digit_mask(word_at(chunk));
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize / 8);
+        }
+    }
+    let tail_start = line.len() - chunks.remainder().len();
+    chunks
+        .remainder()
+        .iter()
+        .position(u8::is_ascii_digit)
+        .map(|i| tail_start + i)
+}
+
+/// Index of the last ASCII digit in `line`.
+pub fn last_digit(line: &[u8]) -> Option<usize> {
+    let mut chunks = line.rchunks_exact(8);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        let mask = digit_mask(word_at(chunk));
+        if mask != 0 {
+            let chunk_start = line.len() - (i + 1) * 8;
+            return Some(chunk_start + 7 - mask.leading_zeros() as usize / 8);
+        }
+    }
+    chunks.remainder().iter().rposition(u8::is_ascii_digit)
+}
+
+/// Part 1 with the word-at-a-time digit search.
+#[tracing::instrument(skip(input))]
+pub fn process_part1(input: &str) -> miette::Result<u32, AocError> {
+    sum_lines(input, |line| {
+        let first = line[first_digit(line)?] - b'0';
+        let last = line[last_digit(line)?] - b'0';
+        Some((u32::from(first), u32::from(last)))
+    })
+}
+
+/// Part 2 checking each position from the front, then from the back, for a
+/// numeral or spelled-out English digit.
+#[tracing::instrument(skip(input))]
+pub fn process_part2(input: &str) -> miette::Result<u32, AocError> {
+    process_with_vocabulary(input, &Vocabulary::english())
+}
+
+pub fn process_with_vocabulary(
+    input: &str,
+    vocabulary: &Vocabulary,
+) -> miette::Result<u32, AocError> {
+    // bucket tokens by their first byte so most positions are rejected by one lookup
+    let mut tokens_by_first_byte: Vec<Vec<(&[u8], u32)>> = vec![Vec::new(); 256];
+    for (token, value) in vocabulary.tokens() {
+        if let Some(first) = token.as_bytes().first() {
+            tokens_by_first_byte[*first as usize].push((token.as_bytes(), *value));
+        }
+    }
+    // the longest token starting at a position wins, as with the Extractor
+    for bucket in &mut tokens_by_first_byte {
+        bucket.sort_by_key(|(token, _)| std::cmp::Reverse(token.len()));
+    }
+    let token_at = |line: &[u8], i: usize| {
+        tokens_by_first_byte[line[i] as usize]
+            .iter()
+            .find(|(token, _)| line[i..].starts_with(token))
+            .map(|(_, value)| *value)
+    };
+    sum_lines(input, |line| {
+        let first = (0..line.len()).find_map(|i| token_at(line, i))?;
+        let last = (0..line.len()).rev().find_map(|i| token_at(line, i))?;
+        Some((first, last))
+    })
+}
+
+fn sum_lines(
+    input: &str,
+    first_and_last: impl Fn(&[u8]) -> Option<(u32, u32)>,
+) -> miette::Result<u32, AocError> {
+    input
+        .as_bytes()
+        .split(|b| *b == b'\n')
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .try_fold(0, |sum, (i, line)| {
+            let (tens, ones) = first_and_last(line)
+                .ok_or_else(|| AocError::ParseError(format!("line {}: no digits found", i + 1)))?;
+            Ok(sum + 10 * tens + ones)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_search() {
+        let line = b"ab/:9cdefgh0ijklmnopq7rs";
+        assert_eq!(Some(4), first_digit(line));
+        assert_eq!(Some(21), last_digit(line));
+        assert_eq!(Some(1), first_digit(b"x1"));
+        assert_eq!(Some(0), last_digit(b"1xxxxxxxxxxxxxxxx"));
+        assert_eq!(None, first_digit(b"/:/:/:/:/:/:pqrstuvw"));
+        assert_eq!(None, last_digit(b""));
+
+        for byte in 0..=u8::MAX {
+            for position in 0..19 {
+                let mut line = [b'x'; 19];
+                line[position] = byte;
+                let expected = byte.is_ascii_digit().then_some(position);
+                assert_eq!(expected, first_digit(&line), "{byte} at {position}");
+                assert_eq!(expected, last_digit(&line), "{byte} at {position}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_nom_solvers() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(crate::part1::process(input)?, process_part1(input)?);
+        assert_eq!(crate::part2::process(input)?, process_part2(input)?);
+        assert!(process_part1("abc").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_extractor_on_shared_prefixes() -> miette::Result<()> {
+        let vocabulary = Vocabulary::new([("a", 1), ("ab", 2), ("abc", 3)])?;
+        let input = "xabcx
+ab
+ba
+aab
+abca";
+        let expected = vocabulary
+            .extractor()?
+            .calibration_values(input)?
+            .iter()
+            .map(|(tens, ones)| 10 * tens + ones)
+            .sum::<u32>();
+        assert_eq!(33 + 22 + 11 + 12 + 31, expected);
+        assert_eq!(expected, process_with_vocabulary(input, &vocabulary)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! Calibration sums over any [`BufRead`], one line of the document at a time.
+//! Sums are `u64` so inputs of billions of lines don't overflow.
+use std::io::BufRead;
+
+use line_stream::for_each_line;
+use nom::combinator::all_consuming;
+
+use crate::{custom_error::AocError, part1, vocabulary::Vocabulary};
+
+pub fn part1<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    sum_calibration_values(reader, |line| {
+        let (_, digits) = all_consuming(part1::parse_line)(line)
+            .map_err(|_| AocError::ParseError(part1::line_error(line)))?;
+        Ok(digits)
+    })
+}
+
+pub fn part2<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    part2_with_vocabulary(reader, &Vocabulary::english())
+}
+
+pub fn part2_with_vocabulary<R: BufRead>(
+    reader: R,
+    vocabulary: &Vocabulary,
+) -> miette::Result<u64, AocError> {
+    let extractor = vocabulary.extractor()?;
+    sum_calibration_values(reader, |line| {
+        extractor
+            .first_and_last(line)
+            .ok_or_else(|| AocError::ParseError("no digits found".to_string()))
+    })
+}
+
+fn sum_calibration_values<R: BufRead>(
+    reader: R,
+    calibration_digits: impl Fn(&str) -> miette::Result<(u32, u32), AocError>,
+) -> miette::Result<u64, AocError> {
+    let mut sum = 0;
+    for_each_line(reader, |line_number, line| {
+        let (tens, ones) = calibration_digits(line)
+            .map_err(|e| AocError::ParseError(format!("line {line_number}: {e}")))?;
+        sum += u64::from(10 * tens + ones);
+        Ok::<_, AocError>(())
+    })?;
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(
+            u64::from(crate::part1::process(input)?),
+            part1(input.as_bytes())?
+        );
+        assert_eq!(
+            u64::from(crate::part2::process(input)?),
+            part2(input.as_bytes())?
+        );
+        Ok(())
+    }
+}
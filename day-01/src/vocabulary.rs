@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use aho_corasick::AhoCorasick;
+
+use crate::custom_error::AocError;
+
+const NUMERALS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+
+/// The tokens that count as digits in a calibration line, and their values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Every value has to be a single digit, `0` to `9`.
+    pub fn new<S: Into<String>>(
+        tokens: impl IntoIterator<Item = (S, u32)>,
+    ) -> miette::Result<Self, AocError> {
+        let tokens = tokens
+            .into_iter()
+            .map(|(token, value)| {
+                let token = token.into();
+                if value > 9 {
+                    return Err(AocError::NotADigit { token, value });
+                }
+                Ok((token, value))
+            })
+            .collect::<miette::Result<_, AocError>>()?;
+        Ok(Vocabulary { tokens })
+    }
+
+    /// Only the numerals `0` to `9`, as in part 1.
+    pub fn numerals() -> Self {
+        Vocabulary {
+            tokens: NUMERALS.map(|(t, v)| (t.to_string(), v)).to_vec(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Vocabulary::numerals().with_words(ENGLISH)
+    }
+
+    pub fn german() -> Self {
+        Vocabulary::numerals().with_words(GERMAN)
+    }
+
+    pub fn french() -> Self {
+        Vocabulary::numerals().with_words(FRENCH)
+    }
+
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "numerals" => Some(Vocabulary::numerals()),
+            "english" | "en" => Some(Vocabulary::english()),
+            "german" | "de" => Some(Vocabulary::german()),
+            "french" | "fr" => Some(Vocabulary::french()),
+            _ => None,
+        }
+    }
+
+    /// Parses `token = value` lines, with values from `0` to `9`. Blank lines and lines starting with `#` are skipped.
+    pub fn parse(contents: &str) -> miette::Result<Self, AocError> {
+        let tokens = contents
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(line_number, line)| {
+                let (token, value) = line
+                    .split_once('=')
+                    .map(|(t, v)| (t.trim(), v.trim()))
+                    .filter(|(t, _)| !t.is_empty())
+                    .ok_or_else(|| {
+                        AocError::ParseError(format!(
+                            "vocabulary line {line_number}: expected `token = value`"
+                        ))
+                    })?;
+                let value = value.parse().map_err(|e| {
+                    AocError::ParseError(format!("vocabulary line {line_number}: {e}"))
+                })?;
+                Ok((token, value))
+            })
+            .collect::<miette::Result<Vec<_>, AocError>>()?;
+        Vocabulary::new(tokens)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> miette::Result<Self, AocError> {
+        Vocabulary::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn tokens(&self) -> &[(String, u32)] {
+        &self.tokens
+    }
+
+    pub fn extractor(&self) -> miette::Result<Extractor, AocError> {
+        let automaton = AhoCorasick::new(self.tokens.iter().map(|(t, _)| t))
+            .map_err(|e| AocError::ParseError(e.to_string()))?;
+        Ok(Extractor {
+            automaton,
+            values: self.tokens.iter().map(|(_, v)| *v).collect(),
+        })
+    }
+
+    fn with_words(mut self, words: [&str; 9]) -> Self {
+        self.tokens
+            .extend(words.iter().zip(1..).map(|(w, v)| (w.to_string(), v)));
+        self
+    }
+}
+
+/// Finds the first and last vocabulary token of a line in a single pass.
+#[derive(Debug, Clone)]
+pub struct Extractor {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Extractor {
+    /// Overlapping tokens are all reported, so `eightwo` gives `(8, 2)`.
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut first: Option<aho_corasick::Match> = None;
+        let mut last: Option<aho_corasick::Match> = None;
+        for m in self.automaton.find_overlapping_iter(line) {
+            if first.is_none_or(|f| (m.start(), f.len()) < (f.start(), m.len())) {
+                first = Some(m);
+            }
+            if last.is_none_or(|l| (m.start(), m.len()) > (l.start(), l.len())) {
+                last = Some(m);
+            }
+        }
+        Some((
+            self.values[first?.pattern().as_usize()],
+            self.values[last?.pattern().as_usize()],
+        ))
+    }
+
+    pub fn calibration_values(&self, input: &str) -> miette::Result<Vec<(u32, u32)>, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                self.first_and_last(line)
+                    .ok_or_else(|| AocError::ParseError(format!("line {}: no digits found", i + 1)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vocabularies() -> miette::Result<()> {
+        let english = Vocabulary::english().extractor()?;
+        assert_eq!(Some((8, 2)), english.first_and_last("eightwo"));
+        assert_eq!(Some((2, 4)), english.first_and_last("xtwone3four"));
+        assert_eq!(None, english.first_and_last("abc"));
+
+        let german = Vocabulary::german().extractor()?;
+        assert_eq!(Some((3, 5)), german.first_and_last("xdrei7fünfz"));
+
+        let french = Vocabulary::french().extractor()?;
+        assert_eq!(Some((9, 1)), french.first_and_last("neufdeuxun"));
+
+        let custom = Vocabulary::parse("# roman\ni = 1\n\nv = 5")?;
+        assert_eq!(2, custom.tokens().len());
+        assert_eq!(Some((5, 1)), custom.extractor()?.first_and_last("avoid"));
+        assert!(Vocabulary::parse("seven").is_err());
+        assert!(matches!(
+            Vocabulary::parse("i = 1\nx = 10"),
+            Err(AocError::NotADigit { value: 10, .. })
+        ));
+        assert!(Vocabulary::new([("ten", 10)]).is_err());
+        Ok(())
+    }
+}
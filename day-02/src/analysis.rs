@@ -0,0 +1,146 @@
+//! Questions about which bags are consistent with a set of observed games.
+use crate::{
+    bag::{Bag, UnknownColors},
+    part1::Game,
+    part2::ideal_round,
+};
+
+/// A game that the bag can't produce, and the color that rules it out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub game: u32,
+    pub color: String,
+    /// The most cubes of the color drawn in a single round of the game.
+    pub drawn: u32,
+    /// `None` if the bag has no limit for the color.
+    pub limit: Option<u32>,
+}
+
+/// A limit that has to be raised, or added, for a game to become possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitChange {
+    pub color: String,
+    pub from: Option<u32>,
+    pub to: u32,
+}
+
+/// The smallest bag that makes every game possible at once.
+pub fn minimal_bag(games: &[Game]) -> Bag {
+    let mut bag = Bag::default();
+    for game in games {
+        for (color, count) in ideal_round(game).cubes() {
+            if bag.limit(color).is_none_or(|limit| limit < count) {
+                bag = bag.with_limit(color, count);
+            }
+        }
+    }
+    bag
+}
+
+/// Every game and color that the bag rules out, in game order.
+///
+/// Colors the bag has no limit for are violations unless the bag ignores them.
+pub fn violations(games: &[Game], bag: &Bag) -> Vec<Violation> {
+    games
+        .iter()
+        .flat_map(|game| {
+            ideal_round(game)
+                .cubes()
+                .filter_map(|(color, drawn)| {
+                    let limit = bag.limit(color);
+                    let violated = match limit {
+                        Some(limit) => drawn > limit,
+                        None => bag.unknown_colors() != UnknownColors::Ignore,
+                    };
+                    violated.then(|| Violation {
+                        game: game.id,
+                        color: color.to_string(),
+                        drawn,
+                        limit,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// The limits to raise so that the bag admits game `id`, which is empty if
+/// it already does. `None` if there is no such game.
+pub fn smallest_change(games: &[Game], bag: &Bag, id: u32) -> Option<Vec<LimitChange>> {
+    let game = games.iter().find(|g| g.id == id)?;
+    let changes = violations(std::slice::from_ref(game), bag)
+        .into_iter()
+        .map(|v| LimitChange {
+            color: v.color,
+            from: v.limit,
+            to: v.drawn,
+        })
+        .collect();
+    Some(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::parse_input;
+
+    const INPUT: &str = include_str!("../input1.txt");
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_minimal_bag() -> miette::Result<()> {
+        let (_, games) = parse_input(EXAMPLE).unwrap();
+        let bag = minimal_bag(&games);
+        assert_eq!(Bag::new([("red", 20), ("green", 13), ("blue", 15)]), bag);
+        assert!(violations(&games, &bag).is_empty());
+
+        let (_, games) = parse_input(INPUT).unwrap();
+        let bag = minimal_bag(&games);
+        for game in &games {
+            assert!(bag.admits(game)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_violations() {
+        let (_, games) = parse_input(EXAMPLE).unwrap();
+        let found = violations(&games, &Bag::standard())
+            .into_iter()
+            .map(|v| (v.game, v.color, v.drawn, v.limit))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (3, "red".to_string(), 20, Some(12)),
+                (4, "blue".to_string(), 15, Some(14)),
+                (4, "red".to_string(), 14, Some(12)),
+            ],
+            found
+        );
+    }
+
+    #[test]
+    fn test_smallest_change() {
+        let (_, games) = parse_input(EXAMPLE).unwrap();
+        let bag = Bag::standard();
+        assert_eq!(Some(vec![]), smallest_change(&games, &bag, 1));
+        assert_eq!(None, smallest_change(&games, &bag, 6));
+        assert_eq!(
+            Some(vec![LimitChange {
+                color: "red".to_string(),
+                from: Some(12),
+                to: 20
+            }]),
+            smallest_change(&games, &bag, 3)
+        );
+
+        let bag = Bag::new([("red", 20)]);
+        let changes = smallest_change(&games, &bag, 3).unwrap();
+        assert_eq!(2, changes.len());
+        assert!(changes.iter().all(|c| c.from.is_none()));
+    }
+}
@@ -0,0 +1,180 @@
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+
+use crate::{custom_error::AocError, part1::Game};
+
+/// The limits from the puzzle text.
+const STANDARD: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+/// What to do with a color the bag has no limit for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownColors {
+    /// Fail with [`AocError::UnknownColor`].
+    #[default]
+    Error,
+    /// The bag holds none of that color, so any game drawing it is impossible.
+    Impossible,
+    /// Skip the color, which is how the original solution behaved.
+    Ignore,
+}
+
+impl FromStr for UnknownColors {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(UnknownColors::Error),
+            "impossible" => Ok(UnknownColors::Impossible),
+            "ignore" => Ok(UnknownColors::Ignore),
+            _ => Err(AocError::ParseError(format!(
+                "unknown color policy `{s}`, expected error, impossible or ignore"
+            ))),
+        }
+    }
+}
+
+/// How many cubes of each color the bag holds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    limits: BTreeMap<String, u32>,
+    unknown: UnknownColors,
+}
+
+impl Bag {
+    pub fn new<S: Into<String>>(limits: impl IntoIterator<Item = (S, u32)>) -> Self {
+        Bag {
+            limits: limits.into_iter().map(|(c, n)| (c.into(), n)).collect(),
+            unknown: UnknownColors::default(),
+        }
+    }
+
+    /// 12 red, 13 green and 14 blue cubes.
+    pub fn standard() -> Self {
+        Bag::new(STANDARD)
+    }
+
+    /// Parses `color = count` lines. Blank lines and lines starting with `#` are skipped.
+    pub fn parse(contents: &str) -> miette::Result<Self, AocError> {
+        let limits = contents
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(line_number, line)| parse_limit(line).map_err(|e| with_line(e, line_number)))
+            .collect::<miette::Result<Vec<_>, AocError>>()?;
+        Ok(Bag::new(limits))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> miette::Result<Self, AocError> {
+        Bag::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn with_limit(mut self, color: impl Into<String>, count: u32) -> Self {
+        self.limits.insert(color.into(), count);
+        self
+    }
+
+    pub fn with_unknown_colors(mut self, policy: UnknownColors) -> Self {
+        self.unknown = policy;
+        self
+    }
+
+    pub fn limit(&self, color: &str) -> Option<u32> {
+        self.limits.get(color).copied()
+    }
+
+    pub fn limits(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.limits.iter().map(|(c, n)| (c.as_str(), *n))
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.limits.keys().map(String::as_str)
+    }
+
+    /// The number of cubes in the bag.
+    pub fn total(&self) -> u32 {
+        self.limits.values().sum()
+    }
+
+    pub fn unknown_colors(&self) -> UnknownColors {
+        self.unknown
+    }
+
+    /// Whether every round of the game could have been drawn from this bag.
+    pub fn admits(&self, game: &Game) -> miette::Result<bool, AocError> {
+        for (color, count) in game.rounds.iter().flat_map(|r| r.cubes()) {
+            let possible = match (self.limit(color), self.unknown) {
+                (Some(limit), _) => count <= limit,
+                (None, UnknownColors::Error) => {
+                    return Err(AocError::UnknownColor {
+                        game: game.id,
+                        color: color.to_string(),
+                    })
+                }
+                (None, UnknownColors::Impossible) => false,
+                (None, UnknownColors::Ignore) => true,
+            };
+            if !possible {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Parses a single `color = count` or `color=count` limit, as given on the command line.
+pub fn parse_limit(limit: &str) -> miette::Result<(String, u32), AocError> {
+    let (color, count) = limit
+        .split_once('=')
+        .map(|(c, n)| (c.trim(), n.trim()))
+        .filter(|(c, _)| !c.is_empty())
+        .ok_or_else(|| AocError::ParseError(format!("expected `color = count`, got `{limit}`")))?;
+    let count = count
+        .parse()
+        .map_err(|e| AocError::ParseError(format!("limit for {color}: {e}")))?;
+    Ok((color.to_string(), count))
+}
+
+fn with_line(error: AocError, line_number: usize) -> AocError {
+    match error {
+        AocError::ParseError(message) => {
+            AocError::ParseError(format!("bag line {line_number}: {message}"))
+        }
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::game;
+
+    #[test]
+    fn test_unknown_colors() -> miette::Result<()> {
+        let (_, game) = game("Game 7: 3 red, 2 purple; 1 blue").unwrap();
+        let bag = Bag::standard();
+        assert!(matches!(
+            bag.admits(&game),
+            Err(AocError::UnknownColor { game: 7, .. })
+        ));
+        let bag = bag.with_unknown_colors(UnknownColors::Impossible);
+        assert!(!bag.admits(&game)?);
+        let bag = bag.with_unknown_colors(UnknownColors::Ignore);
+        assert!(bag.admits(&game)?);
+        let bag = bag.with_limit("purple", 1);
+        assert!(!bag.admits(&game)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let bag = Bag::parse("# the puzzle bag\nred = 12\ngreen=13\n\nblue = 14\n")?;
+        assert_eq!(Bag::standard(), bag);
+        assert!(Bag::parse("red 12").is_err());
+        assert!(Bag::parse("red = lots").is_err());
+        assert_eq!(
+            "impossible".parse::<UnknownColors>()?,
+            UnknownColors::Impossible
+        );
+        Ok(())
+    }
+}
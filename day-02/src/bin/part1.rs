@@ -1,4 +1,8 @@
-use day_02::part1::process;
+use day_02::{
+    bag::{parse_limit, Bag},
+    part1::sum_possible,
+    strict::{parse_games, ParseMode},
+};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -13,8 +17,37 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
+    // `--bag <file>` replaces the standard bag with `color = count` lines,
+    // `--limit color=count` adds or overrides a single limit and
+    // `--unknown error|impossible|ignore` decides what to do with other colors
+    // and `--strict` rejects malformed input instead of skipping it.
+    let mut bag = Bag::standard();
+    let mut mode = ParseMode::Lenient;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--strict" {
+            mode = ParseMode::Strict;
+            continue;
+        }
+        let Some(value) = args.next() else {
+            miette::bail!("`{arg}` needs a value");
+        };
+        bag = match arg.as_str() {
+            "--bag" => Bag::from_file(&value)
+                .context("load bag")?
+                .with_unknown_colors(bag.unknown_colors()),
+            "--limit" => {
+                let (color, count) = parse_limit(&value)?;
+                bag.with_limit(color, count)
+            }
+            "--unknown" => bag.with_unknown_colors(value.parse()?),
+            _ => miette::bail!("unknown argument `{arg}`"),
+        };
+    }
+
     let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let games = parse_games(file, mode).context("process part 1")?;
+    let result = sum_possible(&games, &bag).context("process part 1")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
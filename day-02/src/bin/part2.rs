@@ -1,4 +1,7 @@
-use day_02::part2::process;
+use day_02::{
+    part2::total_power,
+    strict::{parse_games, ParseMode},
+};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -13,8 +16,16 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
+    // `--strict` rejects malformed input instead of skipping it
+    let mode = match std::env::args().nth(1).as_deref() {
+        Some("--strict") => ParseMode::Strict,
+        Some(arg) => miette::bail!("unknown argument `{arg}`"),
+        None => ParseMode::Lenient,
+    };
+
     let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let games = parse_games(file, mode).context("process part 2")?;
+    let result = total_power(&games);
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,60 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use day_02::{
+    bag::{parse_limit, Bag},
+    part1::parse_input,
+    simulate::{log_likelihood, max_likelihood_bag, Simulator},
+};
+use miette::{Context, IntoDiagnostic};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Prints random games drawn from a bag, e.g.
+/// `cargo run -p day-02 --bin simulate -- --limit red=5 --games 10 --seed 1`,
+/// or with `--estimate <total>` the bag of that many cubes that best explains input1.txt.
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut bag = Bag::standard();
+    let mut games = 100;
+    let mut seed = None;
+    let mut estimate = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let Some(value) = args.next() else {
+            miette::bail!("`{arg}` needs a value");
+        };
+        match arg.as_str() {
+            "--bag" => bag = Bag::from_file(&value).context("load bag")?,
+            "--limit" => {
+                let (color, count) = parse_limit(&value)?;
+                bag = bag.with_limit(color, count);
+            }
+            "--games" => games = value.parse().into_diagnostic().context("--games")?,
+            "--seed" => seed = Some(value.parse().into_diagnostic().context("--seed")?),
+            "--estimate" => estimate = Some(value.parse().into_diagnostic().context("--estimate")?),
+            _ => miette::bail!("unknown argument `{arg}`"),
+        }
+    }
+
+    if let Some(total) = estimate {
+        let (_, games) = parse_input(include_str!("../../input1.txt")).unwrap();
+        let bag = max_likelihood_bag(&games, total).context("estimate bag")?;
+        for (color, count) in bag.limits() {
+            println!("{color} = {count}");
+        }
+        let log_likelihood: f64 = games.iter().map(|g| log_likelihood(g, &bag)).sum();
+        println!("# log likelihood {log_likelihood:.3}");
+        return Ok(());
+    }
+
+    let seed = seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    });
+    let mut rng = StdRng::seed_from_u64(seed);
+    let simulator = Simulator::new(&bag).context("simulate games")?;
+    println!("{}", simulator.input(&mut rng, games));
+    Ok(())
+}
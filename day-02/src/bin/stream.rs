@@ -0,0 +1,22 @@
+use std::io;
+
+use day_02::stream;
+use miette::Context;
+
+/// Sums the ids of the games piped to stdin that the standard bag allows
+/// (part 1), or the powers of their smallest bags (part 2), e.g.
+/// `cargo run --release -p day-02 --bin stream -- 1 < input1.txt`
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let part = std::env::args().nth(1).unwrap_or_else(|| "1".to_string());
+    let stdin = io::stdin().lock();
+    let result = match part.as_str() {
+        "1" => stream::part1(stdin).context("stream part 1")?.to_string(),
+        "2" => stream::part2(stdin).context("stream part 2")?.to_string(),
+        _ => miette::bail!("unknown part `{part}`, expected 1 or 2"),
+    };
+    println!("{}", result);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
@@ -6,4 +6,97 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("Error parsing input")]
+    #[diagnostic(code(aoc::nom::error))]
+    NomParseError(String),
+
+    #[error("{0}")]
+    #[diagnostic(code(aoc::parser_error))]
+    ParseError(String),
+
+    #[error("{0}")]
+    #[diagnostic(code(aoc::estimate_error))]
+    EstimateError(String),
+
+    #[error("{0}")]
+    #[diagnostic(code(aoc::invalid_simulation))]
+    InvalidSimulation(String),
+
+    #[error("game {game} draws {color} cubes, which the bag has no limit for")]
+    #[diagnostic(
+        code(aoc::unknown_color),
+        help("add a limit for `{color}` or choose another unknown color policy")
+    )]
+    UnknownColor { game: u32, color: String },
+
+    #[error("found {} problems in the input", problems.len())]
+    #[diagnostic(code(aoc::invalid_input))]
+    InvalidInput {
+        #[source_code]
+        src: String,
+        #[related]
+        problems: Vec<InputProblem>,
+    },
+}
+
+/// Something the strict parser rejects, pointing at where in the input it is.
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq, Eq)]
+pub enum InputProblem {
+    #[error("game has no id")]
+    #[diagnostic(code(aoc::missing_id))]
+    MissingId {
+        #[label("expected `Game N:`")]
+        span: SourceSpan,
+    },
+
+    #[error("game ids must count up from 1")]
+    #[diagnostic(code(aoc::non_sequential_id))]
+    NonSequentialId {
+        expected: u32,
+        #[label("expected game {expected}")]
+        span: SourceSpan,
+    },
+
+    #[error("{color} is drawn twice in one round")]
+    #[diagnostic(code(aoc::duplicate_color))]
+    DuplicateColor {
+        color: String,
+        #[label("first drawn here")]
+        first: SourceSpan,
+        #[label("and again here")]
+        span: SourceSpan,
+    },
+
+    #[error("a round draws zero {color} cubes")]
+    #[diagnostic(
+        code(aoc::zero_count),
+        help("leave the color out of the round instead")
+    )]
+    ZeroCount {
+        color: String,
+        #[label("zero cubes")]
+        span: SourceSpan,
+    },
+
+    #[error("a round draws no cubes")]
+    #[diagnostic(code(aoc::empty_round), help("remove the extra `;`"))]
+    EmptyRound {
+        #[label("empty round")]
+        span: SourceSpan,
+    },
+
+    #[error("game draws no cubes")]
+    #[diagnostic(code(aoc::empty_game))]
+    EmptyGame {
+        #[label("no rounds after the `:`")]
+        span: SourceSpan,
+    },
+
+    #[error("unexpected text in the input")]
+    #[diagnostic(code(aoc::trailing_junk))]
+    TrailingJunk {
+        #[label("not part of a game")]
+        span: SourceSpan,
+    },
 }
@@ -1,3 +1,8 @@
+pub mod analysis;
+pub mod bag;
 pub mod custom_error;
 pub mod part1;
 pub mod part2;
+pub mod simulate;
+pub mod stream;
+pub mod strict;
@@ -1,3 +1,8 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
 use nom::{
     bytes::complete::{tag, tag_no_case},
     character::complete::{alpha1, newline, space0, u32},
@@ -6,34 +11,42 @@ use nom::{
     IResult,
 };
 
-use crate::custom_error::AocError;
-
-const NUM_BLUE: u32 = 14;
-const NUM_RED: u32 = 12;
-const NUM_GREEN: u32 = 13;
+use crate::{bag::Bag, custom_error::AocError};
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
+    process_with_bag(input, &Bag::standard())
+}
+
+#[tracing::instrument(skip(bag))]
+pub fn process_with_bag(input: &str, bag: &Bag) -> miette::Result<u32, AocError> {
     let (_, games) = parse_input(input).unwrap();
+    sum_possible(&games, bag)
+}
 
-    let score = games
-        .iter()
-        .filter_map(|g| {
-            if g.rounds
-                .iter()
-                .any(|r| r.blue > NUM_BLUE || r.green > NUM_GREEN || r.red > NUM_RED)
-            {
-                tracing::error!("Discarding game {}", &g.id);
-                None
-            } else {
-                Some(g.id)
-            }
-        })
-        .sum();
+/// The sum of the ids of the games the bag admits.
+pub fn sum_possible(games: &[Game], bag: &Bag) -> miette::Result<u32, AocError> {
+    let mut score = 0;
+    for game in games {
+        if bag.admits(game)? {
+            score += game.id;
+        } else {
+            tracing::error!("Discarding game {}", &game.id);
+        }
+    }
 
     Ok(score)
 }
 
+/// Every color drawn in any round of the games.
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|g| &g.rounds)
+        .flat_map(|r| r.cubes().map(|(color, _)| color))
+        .collect()
+}
+
 pub fn parse_input(input: &str) -> IResult<&str, Vec<Game>> {
     separated_list1(newline, game)(input)
 }
@@ -56,16 +69,8 @@ pub fn round(input: &str) -> IResult<&str, Round> {
 
     let round = cubes
         .into_iter()
-        .fold(Round::default(), |mut round, (count, color)| {
-            if color == "red" {
-                round.red = count;
-            } else if color == "blue" {
-                round.blue = count;
-            } else if color == "green" {
-                round.green = count;
-            }
-            round
-        });
+        .map(|(count, color)| (color, count))
+        .collect();
     Ok((input, round))
 }
 
@@ -75,16 +80,57 @@ pub struct Game {
     pub rounds: Vec<Round>,
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+/// The cubes drawn in one round, by color. A color drawn twice keeps the last count.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Round {
-    pub blue: u32,
-    pub red: u32,
-    pub green: u32,
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Round {
+    /// How many cubes of the color were drawn, zero if it wasn't.
+    pub fn get(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes.iter().map(|(c, n)| (c.as_str(), *n))
+    }
+}
+
+/// Writes the game back in the puzzle's `Game N: ...; ...` format.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (i, round) in self.rounds.iter().enumerate() {
+            let separator = if i == 0 { " " } else { "; " };
+            write!(f, "{separator}{round}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (color, count)) in self.cubes().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{count} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Round {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        Round {
+            cubes: iter.into_iter().map(|(c, n)| (c.into(), n)).collect(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bag::UnknownColors;
 
     #[test]
     fn test_process() -> miette::Result<()> {
@@ -96,4 +142,36 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
         assert_eq!(8, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_any_colors() -> miette::Result<()> {
+        let input = "Game 1: 3 blue, 4 teal; 2 teal\nGame 2: 5 teal, 1 blue\nGame 3: 1 red";
+        let (_, games) = parse_input(input).unwrap();
+        assert_eq!(
+            vec!["blue", "red", "teal"],
+            colors(&games).into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(4, games[0].rounds[0].get("teal"));
+        assert_eq!(0, games[0].rounds[1].get("blue"));
+
+        let bag = Bag::new([("blue", 3), ("teal", 4)]);
+        assert!(process_with_bag(input, &bag).is_err());
+        let bag = bag.with_unknown_colors(UnknownColors::Impossible);
+        assert_eq!(1, process_with_bag(input, &bag)?);
+        let bag = bag.with_limit("teal", 5).with_limit("red", 1);
+        assert_eq!(6, process_with_bag(input, &bag)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() {
+        let line = "Game 12: 4 red, 3 blue; 1 red, 6 blue, 2 green; 2 green";
+        let (_, parsed) = game(line).unwrap();
+        assert_eq!(
+            "Game 12: 3 blue, 4 red; 6 blue, 2 green, 1 red; 2 green",
+            parsed.to_string()
+        );
+        let (_, reparsed) = game(&parsed.to_string()).unwrap();
+        assert_eq!(parsed.rounds, reparsed.rounds);
+    }
 }
@@ -1,21 +1,32 @@
 use crate::custom_error::AocError;
-use crate::part1::{Round, parse_input};
+use crate::part1::{colors, parse_input, Game, Round};
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
     let (_, games) = parse_input(input).unwrap();
+    Ok(total_power(&games))
+}
+
+pub fn total_power(games: &[Game]) -> u32 {
+    // A game that never draws one of the input's colors needs none of it, so its power is zero
+    let colors = colors(games);
+    games.iter().map(|g| power(g, colors.iter().copied())).sum()
+}
+
+/// The fewest cubes of each color the game could have been played with.
+pub fn ideal_round(game: &Game) -> Round {
+    let mut ideal = std::collections::BTreeMap::new();
+    for (color, count) in game.rounds.iter().flat_map(|r| r.cubes()) {
+        let max = ideal.entry(color).or_insert(0);
+        *max = u32::max(*max, count);
+    }
+    ideal.into_iter().collect()
+}
 
-    let power = games.iter().fold(0, |power, game| {
-        let ideal_round = game.rounds.iter().fold(Round::default(), |ideal_round, round| {
-            Round {
-                blue: u32::max(ideal_round.blue, round.blue),
-                green: u32::max(ideal_round.green, round.green),
-                red: u32::max(ideal_round.red, round.red)
-            }
-        });
-       power + ideal_round.blue * ideal_round.green * ideal_round.red
-    });
-    Ok(power)
+/// The product of the ideal counts of the given colors.
+pub fn power<'a>(game: &Game, colors: impl IntoIterator<Item = &'a str>) -> u32 {
+    let ideal_round = ideal_round(game);
+    colors.into_iter().map(|c| ideal_round.get(c)).product()
 }
 
 #[cfg(test)]
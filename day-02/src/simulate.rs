@@ -0,0 +1,293 @@
+//! Random games drawn from a known bag, and the likelihood of observed games
+//! under a bag. Each round draws cubes without replacement and puts them back
+//! before the next round, so a round follows a multivariate hypergeometric
+//! distribution and the rounds of a game are independent.
+use std::ops::RangeInclusive;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    analysis::minimal_bag,
+    bag::Bag,
+    custom_error::AocError,
+    part1::{Game, Round},
+};
+
+/// Generates games from a bag.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    cubes: Vec<String>,
+    rounds: RangeInclusive<usize>,
+    draw: RangeInclusive<usize>,
+}
+
+impl Simulator {
+    /// Games have 1 to 6 rounds, each drawing between one cube and the whole
+    /// bag. The bag needs at least one cube.
+    pub fn new(bag: &Bag) -> miette::Result<Self, AocError> {
+        let cubes: Vec<String> = bag
+            .limits()
+            .flat_map(|(color, count)| std::iter::repeat_n(color.to_string(), count as usize))
+            .collect();
+        if cubes.is_empty() {
+            return Err(AocError::InvalidSimulation(
+                "the bag has no cubes to draw".to_string(),
+            ));
+        }
+        let draw = 1..=cubes.len();
+        Ok(Simulator {
+            cubes,
+            rounds: 1..=6,
+            draw,
+        })
+    }
+
+    /// Every game needs at least one round.
+    pub fn with_rounds(mut self, rounds: RangeInclusive<usize>) -> miette::Result<Self, AocError> {
+        self.rounds = non_empty("rounds", rounds)?;
+        Ok(self)
+    }
+
+    /// Every round draws at least one cube, and at most the number of cubes
+    /// in the bag.
+    pub fn with_draw_size(mut self, draw: RangeInclusive<usize>) -> miette::Result<Self, AocError> {
+        let draw = non_empty("draw size", draw)?;
+        let max = self.cubes.len();
+        self.draw = (*draw.start()).min(max)..=(*draw.end()).min(max);
+        Ok(self)
+    }
+
+    pub fn round<R: Rng + ?Sized>(&self, rng: &mut R) -> Round {
+        let size = rng.gen_range(self.draw.clone());
+        let mut round = std::collections::BTreeMap::new();
+        for color in self.cubes.choose_multiple(rng, size) {
+            *round.entry(color.as_str()).or_insert(0) += 1;
+        }
+        round.into_iter().collect()
+    }
+
+    pub fn game<R: Rng + ?Sized>(&self, rng: &mut R, id: u32) -> Game {
+        let rounds = rng.gen_range(self.rounds.clone());
+        Game {
+            id,
+            rounds: (0..rounds).map(|_| self.round(rng)).collect(),
+        }
+    }
+
+    /// Games `1..=count`, one per line, in the puzzle's input format.
+    pub fn input<R: Rng + ?Sized>(&self, rng: &mut R, count: u32) -> String {
+        (1..=count)
+            .map(|id| self.game(rng, id).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Games with an empty round or no rounds at all wouldn't parse back.
+fn non_empty(
+    what: &str,
+    range: RangeInclusive<usize>,
+) -> miette::Result<RangeInclusive<usize>, AocError> {
+    if *range.start() == 0 || range.is_empty() {
+        return Err(AocError::InvalidSimulation(format!(
+            "{what} {}..={} has to be a range of at least 1",
+            range.start(),
+            range.end()
+        )));
+    }
+    Ok(range)
+}
+
+/// The natural log of the probability of drawing the round from the bag,
+/// negative infinity if it can't be drawn.
+pub fn round_log_likelihood(round: &Round, bag: &Bag) -> f64 {
+    let drawn: u32 = round.cubes().map(|(_, count)| count).sum();
+    if drawn > bag.total() {
+        return f64::NEG_INFINITY;
+    }
+    let mut log_likelihood = -ln_choose(bag.total(), drawn);
+    for (color, count) in round.cubes() {
+        let available = bag.limit(color).unwrap_or(0);
+        if count > available {
+            return f64::NEG_INFINITY;
+        }
+        log_likelihood += ln_choose(available, count);
+    }
+    log_likelihood
+}
+
+/// The natural log of the probability of every round of the game.
+pub fn log_likelihood(game: &Game, bag: &Bag) -> f64 {
+    game.rounds
+        .iter()
+        .map(|round| round_log_likelihood(round, bag))
+        .sum()
+}
+
+pub fn likelihood(game: &Game, bag: &Bag) -> f64 {
+    log_likelihood(game, bag).exp()
+}
+
+/// The bag of `total` cubes, over the colors seen in the games, under which
+/// the games are most likely.
+///
+/// Starts from the smallest bag that admits every game, shares out the
+/// remaining cubes in proportion to how often each color was drawn, then
+/// moves one cube at a time between colors while that improves the
+/// likelihood. The hill climb finds a local maximum.
+pub fn max_likelihood_bag(games: &[Game], total: u32) -> miette::Result<Bag, AocError> {
+    let minimal = minimal_bag(games);
+    if minimal.total() > total {
+        return Err(AocError::EstimateError(format!(
+            "the games need at least {} cubes, more than {total}",
+            minimal.total()
+        )));
+    }
+
+    let colors: Vec<&str> = minimal.colors().collect();
+    let mut counts: Vec<u32> = minimal.limits().map(|(_, count)| count).collect();
+    let drawn: Vec<u32> = colors
+        .iter()
+        .map(|color| {
+            games
+                .iter()
+                .flat_map(|g| &g.rounds)
+                .map(|r| r.get(color))
+                .sum()
+        })
+        .collect();
+    let all_drawn = drawn.iter().sum::<u32>().max(1);
+    let spare = total - minimal.total();
+    for (count, drawn) in counts.iter_mut().zip(&drawn) {
+        *count += (u64::from(spare) * u64::from(*drawn) / u64::from(all_drawn)) as u32;
+    }
+    // rounding down leaves a few cubes over, which go to the most drawn color
+    let leftover = total - counts.iter().sum::<u32>();
+    if let Some(most) = (0..counts.len()).max_by_key(|&i| drawn[i]) {
+        counts[most] += leftover;
+    }
+
+    let bag_of = |counts: &[u32]| Bag::new(colors.iter().copied().zip(counts.iter().copied()));
+    let score = |counts: &[u32]| {
+        let bag = bag_of(counts);
+        games.iter().map(|g| log_likelihood(g, &bag)).sum::<f64>()
+    };
+
+    let mut best = score(&counts);
+    loop {
+        let mut improved = false;
+        for from in 0..counts.len() {
+            for to in 0..counts.len() {
+                if from == to || counts[from] == 0 {
+                    continue;
+                }
+                counts[from] -= 1;
+                counts[to] += 1;
+                let candidate = score(&counts);
+                if candidate > best {
+                    best = candidate;
+                    improved = true;
+                } else {
+                    counts[from] += 1;
+                    counts[to] -= 1;
+                }
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    Ok(bag_of(&counts))
+}
+
+/// `ln(n choose k)`, summed term by term to stay accurate for large `n`.
+fn ln_choose(n: u32, k: u32) -> f64 {
+    let k = k.min(n - k);
+    (1..=k)
+        .map(|i| (f64::from(n - k + i) / f64::from(i)).ln())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::part1::{game, parse_input};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_likelihood() {
+        let bag = Bag::new([("red", 2), ("blue", 3)]);
+        let (_, g) = game("Game 1: 2 red; 1 red, 1 blue; 2 blue").unwrap();
+        let [rr, rb, bb] = [0, 1, 2].map(|i| round_log_likelihood(&g.rounds[i], &bag).exp());
+        assert_close(0.1, rr);
+        assert_close(0.6, rb);
+        assert_close(0.3, bb);
+        assert_close(0.1 * 0.6 * 0.3, likelihood(&g, &bag));
+
+        let (_, g) = game("Game 2: 3 red").unwrap();
+        assert_eq!(0.0, likelihood(&g, &bag));
+        let (_, g) = game("Game 3: 1 green").unwrap();
+        assert_eq!(0.0, likelihood(&g, &bag));
+    }
+
+    #[test]
+    fn test_generated_games_parse() -> miette::Result<()> {
+        let bag = Bag::standard();
+        let simulator = Simulator::new(&bag)?.with_draw_size(1..=20)?;
+        let input = simulator.input(&mut StdRng::seed_from_u64(2), 100);
+        let (rest, games) = parse_input(&input).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(100, games.len());
+        for (id, g) in (1..).zip(&games) {
+            assert_eq!(id, g.id);
+            assert!(bag.admits(g)?);
+            assert!(g
+                .rounds
+                .iter()
+                .all(|r| (1..=20u32).contains(&r.cubes().map(|(_, n)| n).sum())));
+            assert!(log_likelihood(g, &bag).is_finite());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_simulator_rejects_empty_draws() -> miette::Result<()> {
+        let bag = Bag::standard();
+        assert!(Simulator::new(&Bag::new([("red", 0)])).is_err());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 5..=2;
+        assert!(Simulator::new(&bag)?
+            .with_draw_size(reversed.clone())
+            .is_err());
+        assert!(Simulator::new(&bag)?.with_rounds(reversed).is_err());
+        assert!(Simulator::new(&bag)?.with_draw_size(0..=3).is_err());
+        assert!(Simulator::new(&bag)?.with_rounds(0..=3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_likelihood_bag() -> miette::Result<()> {
+        let bag = Bag::new([("red", 5), ("green", 10), ("blue", 15)]);
+        let simulator = Simulator::new(&bag)?.with_draw_size(1..=10)?;
+        let mut rng = StdRng::seed_from_u64(7);
+        let games: Vec<Game> = (1..=300).map(|id| simulator.game(&mut rng, id)).collect();
+
+        let estimate = max_likelihood_bag(&games, 30)?;
+        assert_eq!(30, estimate.total());
+        for (color, count) in bag.limits() {
+            let estimated = estimate.limit(color).unwrap();
+            assert!(estimated.abs_diff(count) <= 1, "{color}: {estimated}");
+        }
+        assert!(max_likelihood_bag(&games, 10).is_err());
+        Ok(())
+    }
+}
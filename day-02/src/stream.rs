@@ -0,0 +1,97 @@
+//! Game sums over any [`BufRead`], one game line at a time. Sums are `u64`
+//! so inputs of billions of games don't overflow.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::BufRead,
+};
+
+use line_stream::for_each_line;
+use nom::combinator::all_consuming;
+
+use crate::{
+    bag::Bag,
+    custom_error::AocError,
+    part1::{self, Game},
+    part2,
+};
+
+pub fn part1<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    part1_with_bag(reader, &Bag::standard())
+}
+
+pub fn part1_with_bag<R: BufRead>(reader: R, bag: &Bag) -> miette::Result<u64, AocError> {
+    fold_games(reader, 0, |sum, game| {
+        if bag.admits(&game)? {
+            *sum += u64::from(game.id);
+        }
+        Ok(())
+    })
+}
+
+/// Takes the power over every color in the input, like [`part2::process`],
+/// where a game missing one of them has a power of zero. The colors are only
+/// known at the end, so powers are summed per set of colors a game drew and
+/// only the sum for the games that drew every color is kept.
+pub fn part2<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    let sums = fold_games(reader, BTreeMap::new(), |sums, game| {
+        let ideal = part2::ideal_round(&game);
+        let colors: Vec<String> = ideal.cubes().map(|(c, _)| c.to_string()).collect();
+        let power: u32 = ideal.cubes().map(|(_, count)| count).product();
+        *sums.entry(colors).or_insert(0) += u64::from(power);
+        Ok(())
+    })?;
+    let num_colors = sums.keys().flatten().collect::<BTreeSet<_>>().len();
+    Ok(sums
+        .iter()
+        .filter(|(colors, _)| colors.len() == num_colors)
+        .map(|(_, sum)| sum)
+        .sum())
+}
+
+/// Takes the power over the colors the bag has limits for.
+pub fn part2_with_colors<R: BufRead>(reader: R, bag: &Bag) -> miette::Result<u64, AocError> {
+    fold_games(reader, 0, |sum, game| {
+        *sum += u64::from(part2::power(&game, bag.colors()));
+        Ok(())
+    })
+}
+
+fn fold_games<R: BufRead, T>(
+    reader: R,
+    mut acc: T,
+    f: impl Fn(&mut T, Game) -> miette::Result<(), AocError>,
+) -> miette::Result<T, AocError> {
+    for_each_line(reader, |line_number, line| {
+        let (_, game) = all_consuming(part1::game)(line)
+            .map_err(|e| AocError::NomParseError(format!("line {line_number}: {e}")))?;
+        f(&mut acc, game)
+    })?;
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(
+            u64::from(crate::part1::process(input)?),
+            part1(input.as_bytes())?
+        );
+        assert_eq!(
+            u64::from(crate::part2::process(input)?),
+            part2(input.as_bytes())?
+        );
+
+        // game 2 never draws yellow, so its power is zero
+        let input = "Game 1: 2 red, 3 yellow; 1 blue\nGame 2: 4 red, 5 blue\nGame 3: 1 red, 1 yellow, 2 blue";
+        assert_eq!(
+            u64::from(crate::part2::process(input)?),
+            part2(input.as_bytes())?
+        );
+        assert_eq!(6 + 2, part2(input.as_bytes())?);
+        Ok(())
+    }
+}
@@ -0,0 +1,274 @@
+//! A parser that rejects malformed input with a diagnostic for every
+//! problem it finds, instead of stopping at the first line it can't read.
+use std::{collections::HashMap, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space0, u32},
+    combinator::{consumed, opt},
+    multi::separated_list0,
+    sequence::{delimited, separated_pair, terminated, tuple},
+    IResult, Offset,
+};
+
+use crate::{
+    custom_error::{AocError, InputProblem},
+    part1::{parse_input, Game},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Reads games until the first line that isn't one and ignores the rest.
+    /// A color drawn twice in a round keeps its last count.
+    #[default]
+    Lenient,
+    /// Every line has to be a well formed game, numbered from 1.
+    Strict,
+}
+
+impl FromStr for ParseMode {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lenient" => Ok(ParseMode::Lenient),
+            "strict" => Ok(ParseMode::Strict),
+            _ => Err(AocError::ParseError(format!(
+                "unknown parse mode `{s}`, expected lenient or strict"
+            ))),
+        }
+    }
+}
+
+pub fn parse_games(input: &str, mode: ParseMode) -> miette::Result<Vec<Game>, AocError> {
+    match mode {
+        ParseMode::Lenient => parse_input(input)
+            .map(|(_, games)| games)
+            .map_err(|e| AocError::NomParseError(e.to_string())),
+        ParseMode::Strict => parse_strict(input),
+    }
+}
+
+/// Fails with [`AocError::InvalidInput`] listing duplicate colors, zero
+/// counts, empty rounds and games, missing or out of order ids and text
+/// that isn't part of a game.
+pub fn parse_strict(input: &str) -> miette::Result<Vec<Game>, AocError> {
+    let mut games = vec![];
+    let mut problems = vec![];
+    let mut expected = 1;
+    let mut start = 0;
+    for raw_line in input.split_inclusive('\n') {
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+        let line_start = start;
+        start += raw_line.len();
+        let span = |part: &str| (line_start + line.offset(part), part.len()).into();
+
+        if line.trim().is_empty() {
+            if !input[start..].trim().is_empty() {
+                problems.push(InputProblem::TrailingJunk {
+                    span: (line_start, raw_line.len()).into(),
+                });
+            }
+            continue;
+        }
+
+        let Ok((rest, ((header, id), rounds))) = game_with_spans(line) else {
+            problems.push(InputProblem::TrailingJunk { span: span(line) });
+            continue;
+        };
+        match id {
+            None => problems.push(InputProblem::MissingId { span: span(header) }),
+            Some((digits, id)) if id != expected => {
+                problems.push(InputProblem::NonSequentialId {
+                    expected,
+                    span: span(digits),
+                });
+                expected = id;
+            }
+            Some(_) => {}
+        }
+        if !rest.is_empty() {
+            problems.push(InputProblem::TrailingJunk { span: span(rest) });
+        }
+
+        if rounds.iter().all(|(_, cubes)| cubes.is_empty()) {
+            problems.push(InputProblem::EmptyGame { span: span(line) });
+        } else {
+            for (text, _) in rounds.iter().filter(|(_, cubes)| cubes.is_empty()) {
+                problems.push(InputProblem::EmptyRound { span: span(text) });
+            }
+        }
+        for (_, cubes) in &rounds {
+            let mut seen = HashMap::new();
+            for &(text, (count, color)) in cubes {
+                if count == 0 {
+                    problems.push(InputProblem::ZeroCount {
+                        color: color.to_string(),
+                        span: span(text),
+                    });
+                }
+                if let Some(first) = seen.insert(color, text) {
+                    problems.push(InputProblem::DuplicateColor {
+                        color: color.to_string(),
+                        first: span(first),
+                        span: span(text),
+                    });
+                }
+            }
+        }
+
+        games.push(Game {
+            id: id.map_or(expected, |(_, id)| id),
+            rounds: rounds
+                .into_iter()
+                .map(|(_, cubes)| cubes.into_iter().map(|(_, (n, c))| (c, n)).collect())
+                .collect(),
+        });
+        expected += 1;
+    }
+
+    if problems.is_empty() {
+        Ok(games)
+    } else {
+        Err(AocError::InvalidInput {
+            src: input.to_string(),
+            problems,
+        })
+    }
+}
+
+type Cube<'a> = (&'a str, (u32, &'a str));
+type Header<'a> = (&'a str, Option<(&'a str, u32)>);
+type Round<'a> = (&'a str, Vec<Cube<'a>>);
+
+/// A game that keeps the text of its id, of every round and of every cube,
+/// to point diagnostics at.
+fn game_with_spans(input: &str) -> IResult<&str, (Header<'_>, Vec<Round<'_>>)> {
+    let header = consumed(delimited(
+        terminated(tag("Game"), space0),
+        opt(consumed(u32)),
+        tuple((space0, tag(":"))),
+    ));
+    let cube = delimited(
+        space0,
+        consumed(separated_pair(u32, space0, alpha1)),
+        space0,
+    );
+    let round = consumed(delimited(space0, separated_list0(tag(","), cube), space0));
+    let rounds = separated_list0(tag(";"), round);
+    tuple((header, rounds))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    fn problems(input: &str) -> Vec<InputProblem> {
+        match parse_strict(input) {
+            Err(AocError::InvalidInput { problems, .. }) => problems,
+            other => panic!("expected problems, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_valid_input() -> miette::Result<()> {
+        let strict = parse_strict(EXAMPLE)?;
+        let lenient = parse_games(EXAMPLE, ParseMode::Lenient)?;
+        assert_eq!(5, strict.len());
+        for (s, l) in strict.iter().zip(&lenient) {
+            assert_eq!((s.id, &s.rounds), (l.id, &l.rounds));
+        }
+        assert_eq!(100, parse_strict(include_str!("../input1.txt"))?.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_problems() {
+        let input = "Game 1: 3 blue, 4 red, 1 blue\nGame 3: 0 green\nGame: 2 red\nGame 5: 1 red!\n";
+        assert_eq!(
+            vec![
+                InputProblem::DuplicateColor {
+                    color: "blue".to_string(),
+                    first: (8, 6).into(),
+                    span: (23, 6).into(),
+                },
+                InputProblem::NonSequentialId {
+                    expected: 2,
+                    span: (35, 1).into(),
+                },
+                InputProblem::ZeroCount {
+                    color: "green".to_string(),
+                    span: (38, 7).into(),
+                },
+                InputProblem::MissingId {
+                    span: (46, 5).into(),
+                },
+            ],
+            problems(input)[..4]
+        );
+        assert_eq!(
+            InputProblem::TrailingJunk {
+                span: (71, 1).into()
+            },
+            problems(input)[4]
+        );
+    }
+
+    #[test]
+    fn test_empty_rounds() {
+        assert_eq!(
+            vec![InputProblem::EmptyRound {
+                span: (7, 1).into()
+            }],
+            problems("Game 1: ;3 red\n")
+        );
+        assert_eq!(
+            vec![
+                InputProblem::EmptyRound {
+                    span: (14, 0).into()
+                },
+                InputProblem::EmptyRound {
+                    span: (15, 0).into()
+                },
+            ],
+            problems("Game 1: 1 red;;\n")
+        );
+        assert_eq!(
+            vec![InputProblem::EmptyGame {
+                span: (0, 7).into()
+            }],
+            problems("Game 1:\n")
+        );
+        assert_eq!(
+            vec![InputProblem::EmptyGame {
+                span: (0, 9).into()
+            }],
+            problems("Game 1: ;\n")
+        );
+    }
+
+    #[test]
+    fn test_trailing_junk() {
+        let input = "Game 1: 1 red\n\nGame 2: 1 red\nthat's all\n\n";
+        assert_eq!(
+            vec![
+                InputProblem::TrailingJunk {
+                    span: (14, 1).into()
+                },
+                InputProblem::TrailingJunk {
+                    span: (29, 10).into()
+                },
+            ],
+            problems(input)
+        );
+        // the lenient parser stops at the blank line
+        assert_eq!(1, parse_games(input, ParseMode::Lenient).unwrap().len());
+    }
+}
@@ -1,4 +1,4 @@
-use day_03::part2::process;
+use day_03::{dump::Dumper, part2::process_with_dump};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -13,8 +13,16 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
+    // `--dump-dir <dir>` writes the intermediate data to text files in `dir`
+    let dumper = match (std::env::args().nth(1).as_deref(), std::env::args().nth(2)) {
+        (Some("--dump-dir"), Some(dir)) => Dumper::to_dir(dir),
+        (Some("--dump-dir"), None) => miette::bail!("`--dump-dir` needs a directory"),
+        (Some(arg), _) => miette::bail!("unknown argument `{arg}`"),
+        (None, _) => Dumper::disabled(),
+    };
+
     let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let result = process_with_dump(file, &dumper).context("process part 2")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,46 @@
+use day_03::{custom_error::AocError, render::schematic_frame, schematic::Schematic};
+use miette::Context;
+
+/// Prints the schematic with counted numbers in green, uncounted numbers in
+/// red and gears highlighted. `--rulers` adds row and column indices and
+/// `--gif <path>` also writes the picture to a GIF (needs the `gif` feature).
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut input_path = None;
+    let mut rulers = false;
+    let mut gif_path: Option<String> = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rulers" => rulers = true,
+            "--gif" => match args.next() {
+                Some(path) if !path.starts_with("--") => gif_path = Some(path),
+                _ => miette::bail!("`--gif` needs a path"),
+            },
+            _ if arg.starts_with("--") => miette::bail!("unknown argument `{arg}`"),
+            _ if input_path.is_some() => miette::bail!("unexpected argument `{arg}`"),
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path).map_err(AocError::from)?,
+        None => include_str!("../../input1.txt").to_string(),
+    };
+    let schematic = Schematic::parse(&input).context("parse schematic")?;
+    let frame = schematic_frame(&input, &schematic, rulers);
+    print!("{}", frame.render_ansi());
+
+    if let Some(path) = gif_path {
+        #[cfg(feature = "gif")]
+        {
+            let mut animation = grid_viz::Animation::new(std::time::Duration::from_secs(1));
+            animation.push(frame);
+            animation.write_gif(path, 4).context("write gif")?;
+        }
+        #[cfg(not(feature = "gif"))]
+        tracing::warn!("not writing {path}: rebuild with `--features gif`");
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! Named intermediate data from the solvers, for when an answer is wrong.
+//!
+//! Every artifact is logged at debug level, and written to `<dir>/<name>.txt`
+//! with one `{:?}` item per line only when a directory has been set. The
+//! default writes nothing, so tests and benches never touch the filesystem.
+use std::{fmt::Debug, path::PathBuf};
+
+use crate::custom_error::AocError;
+
+#[derive(Debug, Clone, Default)]
+pub struct Dumper {
+    dir: Option<PathBuf>,
+}
+
+impl Dumper {
+    pub fn disabled() -> Self {
+        Dumper::default()
+    }
+
+    /// Writes artifacts into `dir`, creating it on the first dump.
+    pub fn to_dir(dir: impl Into<PathBuf>) -> Self {
+        Dumper {
+            dir: Some(dir.into()),
+        }
+    }
+
+    pub fn dump<T: Debug>(
+        &self,
+        name: &str,
+        items: impl IntoIterator<Item = T>,
+    ) -> miette::Result<(), AocError> {
+        let lines: Vec<String> = items.into_iter().map(|item| format!("{item:?}")).collect();
+        tracing::debug!(artifact = name, items = lines.len(), "dump");
+        let Some(dir) = &self.dir else {
+            return Ok(());
+        };
+        std::fs::create_dir_all(dir)?;
+        let mut contents = lines.join("\n");
+        contents.push('\n');
+        std::fs::write(dir.join(format!("{name}.txt")), contents)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_writes_nothing() -> miette::Result<()> {
+        Dumper::disabled().dump("numbers", [1, 2, 3])?;
+        assert!(!std::path::Path::new("numbers.txt").exists());
+        Ok(())
+    }
+}
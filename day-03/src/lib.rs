@@ -1,3 +1,6 @@
 pub mod custom_error;
+pub mod dump;
 pub mod part1;
 pub mod part2;
+pub mod render;
+pub mod schematic;
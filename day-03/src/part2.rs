@@ -1,8 +1,58 @@
-use crate::custom_error::AocError;
+use crate::{
+    custom_error::AocError,
+    dump::Dumper,
+    schematic::{NumberSpan, Schematic},
+};
 
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    todo!("day 01 - part 1");
+pub fn process(input: &str) -> miette::Result<u32, AocError> {
+    process_with_dump(input, &Dumper::disabled())
+}
+
+/// Dumps the `gears`.
+#[tracing::instrument(skip(input))]
+pub fn process_with_dump(input: &str, dumper: &Dumper) -> miette::Result<u32, AocError> {
+    let gears = gears(input)?;
+    tracing::debug!(gears = gears.len(), "found gears");
+    dumper.dump("gears", &gears)?;
+    let sum_of_ratios = gears.iter().map(Gear::ratio).sum();
+    Ok(sum_of_ratios)
+}
+
+/// A `*` next to exactly two numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gear {
+    pub row: usize,
+    pub col: usize,
+    pub numbers: [NumberSpan; 2],
+}
+
+impl Gear {
+    pub fn ratio(&self) -> u32 {
+        self.numbers[0].value * self.numbers[1].value
+    }
+}
+
+/// Every gear in reading order, with its two numbers in reading order.
+pub fn gears(input: &str) -> miette::Result<Vec<Gear>, AocError> {
+    let schematic = Schematic::parse(input)?;
+    Ok(schematic_gears(&schematic))
+}
+
+pub fn schematic_gears(schematic: &Schematic) -> Vec<Gear> {
+    schematic
+        .symbols_of_kind('*')
+        .filter_map(
+            |symbol| match schematic.numbers_adjacent_to(symbol.row, symbol.col)[..] {
+                [first, second] => Some(Gear {
+                    row: symbol.row,
+                    col: symbol.col,
+                    numbers: [*first, *second],
+                }),
+                _ => None,
+            },
+        )
+        .collect()
 }
 
 #[cfg(test)]
@@ -11,9 +61,28 @@ mod tests {
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let input = include_str!("../test-input1.txt");
+        assert_eq!(467835, process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gears() -> miette::Result<()> {
+        let input = include_str!("../test-input1.txt");
+        let found = gears(input)?
+            .iter()
+            .map(|g| (g.row, g.col, g.numbers.map(|n| n.value), g.ratio()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(1, 3, [467, 35], 16345), (8, 5, [755, 598], 451490)],
+            found
+        );
+
+        // the same number on both sides of a `*` is two numbers, three isn't a gear
+        let input = "2*2\n...\n1*1\n.1.";
+        let found = gears(input)?;
+        assert_eq!(1, found.len());
+        assert_eq!(4, found[0].ratio());
         Ok(())
     }
 }
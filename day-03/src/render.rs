@@ -0,0 +1,93 @@
+use grid_viz::{Cell, Color, Frame, Style};
+
+use crate::{part2::schematic_gears, schematic::Schematic};
+
+/// Draws the schematic with part numbers in green, numbers that aren't
+/// counted in red and gears on a yellow background. With `rulers` the
+/// column index runs along the top and the row index down the left.
+pub fn schematic_frame(input: &str, schematic: &Schematic, rulers: bool) -> Frame {
+    let rows = input.lines().count();
+    let cols = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+    let row_label_width = if rulers {
+        rows.saturating_sub(1).to_string().len() + 1
+    } else {
+        0
+    };
+    let (top, left) = if rulers { (2, row_label_width) } else { (0, 0) };
+    let mut frame = Frame::new(rows + top, cols + left);
+
+    let dim = Style::default().fg(Color::GREY);
+    for (row, line) in input.lines().enumerate() {
+        for (col, glyph) in line.chars().enumerate() {
+            let style = if glyph == '.' {
+                dim
+            } else {
+                Style::default().fg(Color::WHITE).bold()
+            };
+            frame.set(row + top, col + left, Cell::styled(glyph, style));
+        }
+    }
+
+    for number in schematic.numbers() {
+        let counted = !schematic.symbols_adjacent_to_number(number.id).is_empty();
+        let color = if counted { Color::GREEN } else { Color::RED };
+        for (row, col) in number.cells() {
+            if let Some(cell) = frame.get_mut(row + top, col + left) {
+                cell.style = Style::default().fg(color).bold();
+            }
+        }
+    }
+
+    for gear in schematic_gears(schematic) {
+        if let Some(cell) = frame.get_mut(gear.row + top, gear.col + left) {
+            cell.style = Style::default().fg(Color::BLACK).bg(Color::YELLOW).bold();
+        }
+    }
+
+    if rulers {
+        for col in 0..cols {
+            if col % 10 == 0 {
+                let tens = char::from_digit((col / 10 % 10) as u32, 10).unwrap_or(' ');
+                frame.set(0, col + left, Cell::styled(tens, dim));
+            }
+            let units = char::from_digit((col % 10) as u32, 10).unwrap_or(' ');
+            frame.set(1, col + left, Cell::styled(units, dim));
+        }
+        for row in 0..rows {
+            let label = format!("{row:>width$} ", width = row_label_width - 1);
+            for (col, glyph) in label.chars().enumerate() {
+                frame.set(row + top, col, Cell::styled(glyph, dim));
+            }
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schematic_frame() -> miette::Result<()> {
+        let input = include_str!("../test-input1.txt");
+        let schematic = Schematic::parse(input)?;
+
+        let frame = schematic_frame(input, &schematic, false);
+        assert_eq!(input.trim_end(), frame.to_string().trim_end());
+        // 467 is counted, 114 isn't and the `*` between 467 and 35 is a gear
+        assert_eq!(Some(Color::GREEN), frame.get(0, 0).unwrap().style.fg);
+        assert_eq!(Some(Color::RED), frame.get(0, 5).unwrap().style.fg);
+        assert_eq!(Some(Color::YELLOW), frame.get(1, 3).unwrap().style.bg);
+        // the `*` next to only 617 isn't
+        assert_eq!(None, frame.get(4, 3).unwrap().style.bg);
+
+        let frame = schematic_frame(input, &schematic, true);
+        let text = frame.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!("  0         ", lines[0]);
+        assert_eq!("  0123456789", lines[1]);
+        assert_eq!("0 467..114..", lines[2]);
+        assert_eq!(Some(Color::GREEN), frame.get(2, 2).unwrap().style.fg);
+        Ok(())
+    }
+}
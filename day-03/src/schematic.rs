@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use nom::character::complete::u32;
+
+use crate::custom_error::AocError;
+
+/// Any character other than a digit or `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A number in the schematic and the cells it covers on its row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSpan {
+    /// The index of the number in reading order, unique even if the value isn't.
+    pub id: usize,
+    pub value: u32,
+    pub row: usize,
+    pub start_col: usize,
+    /// One past the last column.
+    pub end_col: usize,
+}
+
+impl NumberSpan {
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.start_col..self.end_col).map(|col| (self.row, col))
+    }
+}
+
+/// How the numbers next to one kind of symbol add up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KindSummary {
+    pub symbols: usize,
+    /// Distinct numbers next to at least one symbol of the kind.
+    pub numbers: usize,
+    pub sum: u32,
+}
+
+/// The numbers and symbols of an engine schematic, indexed by the cells they cover.
+#[derive(Debug, Clone, Default)]
+pub struct Schematic {
+    symbols: Vec<Symbol>,
+    numbers: Vec<NumberSpan>,
+    symbol_at: HashMap<(usize, usize), usize>,
+    number_at: HashMap<(usize, usize), usize>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> miette::Result<Self, AocError> {
+        let mut schematic = Schematic::default();
+
+        for (row, l) in input.lines().enumerate() {
+            let mut col = 0;
+            let mut rest = l;
+            while let Some(c) = rest.chars().next() {
+                if c.is_ascii_digit() {
+                    let (after, value) = u32::<_, nom::error::Error<_>>(rest)
+                        .map_err(|e| AocError::NomParseError(format!("line {}: {e}", row + 1)))?;
+                    let span = NumberSpan {
+                        id: schematic.numbers.len(),
+                        value,
+                        row,
+                        start_col: col,
+                        end_col: col + rest.len() - after.len(),
+                    };
+                    schematic
+                        .number_at
+                        .extend(span.cells().map(|cell| (cell, span.id)));
+                    schematic.numbers.push(span);
+                    col = span.end_col;
+                    rest = after;
+                } else {
+                    if c != '.' {
+                        schematic
+                            .symbol_at
+                            .insert((row, col), schematic.symbols.len());
+                        schematic.symbols.push(Symbol { kind: c, row, col });
+                    }
+                    col += 1;
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        Ok(schematic)
+    }
+
+    /// Every symbol in reading order.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// Every number in reading order, so a number's id is its index.
+    pub fn numbers(&self) -> &[NumberSpan] {
+        &self.numbers
+    }
+
+    pub fn number(&self, id: usize) -> Option<&NumberSpan> {
+        self.numbers.get(id)
+    }
+
+    /// The number covering the cell, if any.
+    pub fn number_at(&self, row: usize, col: usize) -> Option<&NumberSpan> {
+        self.number_at.get(&(row, col)).map(|&id| &self.numbers[id])
+    }
+
+    pub fn symbol_at(&self, row: usize, col: usize) -> Option<&Symbol> {
+        self.symbol_at.get(&(row, col)).map(|&i| &self.symbols[i])
+    }
+
+    pub fn symbols_of_kind(&self, kind: char) -> impl Iterator<Item = &Symbol> {
+        self.symbols
+            .iter()
+            .filter(move |symbol| symbol.kind == kind)
+    }
+
+    /// The distinct numbers touching the cell at `(row, col)`, in reading order.
+    pub fn numbers_adjacent_to(&self, row: usize, col: usize) -> Vec<&NumberSpan> {
+        neighbours(row, col)
+            .filter_map(|(r, c)| self.number_at.get(&(r, c)).copied())
+            .collect::<BTreeSet<usize>>()
+            .into_iter()
+            .map(|id| &self.numbers[id])
+            .collect()
+    }
+
+    /// The symbols touching any digit of number `id`, in reading order.
+    pub fn symbols_adjacent_to_number(&self, id: usize) -> Vec<&Symbol> {
+        let Some(number) = self.number(id) else {
+            return vec![];
+        };
+        number
+            .cells()
+            .flat_map(|(row, col)| neighbours(row, col))
+            .filter_map(|cell| self.symbol_at.get(&cell).copied())
+            .collect::<BTreeSet<usize>>()
+            .into_iter()
+            .map(|i| &self.symbols[i])
+            .collect()
+    }
+
+    /// The numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &NumberSpan> {
+        self.numbers
+            .iter()
+            .filter(|number| !self.symbols_adjacent_to_number(number.id).is_empty())
+    }
+
+    /// For each kind of symbol, how many there are and the numbers around them.
+    pub fn summary_by_kind(&self) -> BTreeMap<char, KindSummary> {
+        let mut numbers_by_kind: BTreeMap<char, BTreeSet<usize>> = BTreeMap::new();
+        let mut summary: BTreeMap<char, KindSummary> = BTreeMap::new();
+        for symbol in &self.symbols {
+            summary.entry(symbol.kind).or_default().symbols += 1;
+            numbers_by_kind.entry(symbol.kind).or_default().extend(
+                self.numbers_adjacent_to(symbol.row, symbol.col)
+                    .iter()
+                    .map(|number| number.id),
+            );
+        }
+        for (kind, ids) in numbers_by_kind {
+            let kind_summary = summary.entry(kind).or_default();
+            kind_summary.numbers = ids.len();
+            kind_summary.sum = ids.iter().map(|&id| self.numbers[id].value).sum();
+        }
+        summary
+    }
+}
+
+/// The 8 cells around `(row, col)`, skipping any that would be off the top or left edge.
+pub fn neighbours(row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+    (-1..=1isize)
+        .flat_map(|dr| (-1..=1isize).map(move |dc| (dr, dc)))
+        .filter(|&offset| offset != (0, 0))
+        .filter_map(move |(dr, dc)| {
+            Some((row.checked_add_signed(dr)?, col.checked_add_signed(dc)?))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries() -> miette::Result<()> {
+        let schematic = Schematic::parse(include_str!("../test-input1.txt"))?;
+        assert_eq!(10, schematic.numbers().len());
+        assert_eq!(6, schematic.symbols().len());
+
+        let values =
+            |numbers: Vec<&NumberSpan>| numbers.iter().map(|n| n.value).collect::<Vec<_>>();
+        assert_eq!(vec![467, 35], values(schematic.numbers_adjacent_to(1, 3)));
+        assert_eq!(vec![592], values(schematic.numbers_adjacent_to(5, 5)));
+
+        let temp = schematic.number_at(2, 7).unwrap();
+        assert_eq!((633, 6, 9), (temp.value, temp.start_col, temp.end_col));
+        let symbols = schematic.symbols_adjacent_to_number(temp.id);
+        assert_eq!(
+            vec![('#', 3, 6)],
+            symbols
+                .iter()
+                .map(|s| (s.kind, s.row, s.col))
+                .collect::<Vec<_>>()
+        );
+        assert!(schematic.symbols_adjacent_to_number(1).is_empty());
+        assert!(schematic.symbols_adjacent_to_number(99).is_empty());
+
+        assert_eq!(3, schematic.symbols_of_kind('*').count());
+        assert_eq!(Some('$'), schematic.symbol_at(8, 3).map(|s| s.kind));
+        assert_eq!(8, schematic.part_numbers().count());
+
+        let summary = schematic.summary_by_kind();
+        assert_eq!(
+            KindSummary {
+                symbols: 3,
+                numbers: 5,
+                sum: 467 + 35 + 617 + 755 + 598
+            },
+            summary[&'*']
+        );
+        assert_eq!(
+            vec!['#', '$', '*', '+'],
+            summary.keys().copied().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
@@ -19,4 +19,54 @@ fn part2() {
         "../input2.txt",
     )))
     .unwrap();
-}
\ No newline at end of file
+}
+#[cfg(feature = "no-alloc")]
+#[divan::bench]
+fn part1_no_alloc() {
+    no_alloc::part1(divan::black_box(include_bytes!("../input1.txt"))).unwrap();
+}
+
+#[cfg(feature = "no-alloc")]
+#[divan::bench]
+fn part2_no_alloc() {
+    no_alloc::part2(divan::black_box(include_bytes!("../input2.txt"))).unwrap();
+}
+
+/// Match counting only, on cards parsed up front: the `u128` popcount the
+/// solvers use against the `HashMap` lookups they replaced.
+#[divan::bench]
+fn matches_bitset(bencher: divan::Bencher) {
+    let cards = card::parse_cards(include_str!("../input1.txt")).unwrap();
+    bencher.bench(|| {
+        divan::black_box(&cards)
+            .iter()
+            .map(|card| card.num_matches())
+            .sum::<u32>()
+    });
+}
+
+#[divan::bench]
+fn matches_hashmap(bencher: divan::Bencher) {
+    let cards: Vec<(Vec<u8>, std::collections::HashMap<u8, ()>)> =
+        card::parse_cards(include_str!("../input1.txt"))
+            .unwrap()
+            .iter()
+            .map(|card| {
+                (
+                    card.winning_numbers.iter().collect(),
+                    card.available_numbers.iter().map(|n| (n, ())).collect(),
+                )
+            })
+            .collect();
+    bencher.bench(|| {
+        divan::black_box(&cards)
+            .iter()
+            .map(|(winning_numbers, available_numbers)| {
+                winning_numbers
+                    .iter()
+                    .filter(|num| available_numbers.contains_key(num))
+                    .count() as u32
+            })
+            .sum::<u32>()
+    });
+}
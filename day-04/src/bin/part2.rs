@@ -1,4 +1,4 @@
-use day_04::part2::process;
+use day_04::{card::parse_cards, cascade::cascade};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -14,7 +14,17 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
-    println!("{}", result);
+    let cards = parse_cards(file).context("process part 2")?;
+    let cascade = cascade(&cards).context("process part 2")?;
+
+    // `--trace csv` or `--trace json` prints every card instead of the total
+    match std::env::args().skip(1).collect::<Vec<_>>().as_slice() {
+        [] => println!("{}", cascade.total_cards),
+        [flag, format] if flag == "--trace" && format == "csv" => print!("{}", cascade.to_csv()),
+        [flag, format] if flag == "--trace" && format == "json" => {
+            println!("{}", cascade.to_json().context("trace part 2")?)
+        }
+        _ => miette::bail!("expected no arguments or `--trace csv|json`"),
+    }
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,311 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    ops::BitAnd,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{space0, space1, u32, u8},
+    combinator::{consumed, verify},
+    multi::separated_list1,
+    sequence::{delimited, separated_pair, tuple},
+    IResult, Offset,
+};
+
+use crate::custom_error::{AocError, CardProblem};
+
+/// A set of numbers below 128, one bit each.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NumberSet(u128);
+
+impl NumberSet {
+    pub const MAX: u8 = 127;
+
+    pub fn insert(&mut self, number: u8) {
+        debug_assert!(number <= Self::MAX);
+        self.0 |= 1 << number;
+    }
+
+    pub fn contains(&self, number: u8) -> bool {
+        number <= Self::MAX && self.0 & (1 << number) != 0
+    }
+
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The numbers in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=Self::MAX).filter(|&n| self.contains(n))
+    }
+}
+
+impl BitAnd for NumberSet {
+    type Output = NumberSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        NumberSet(self.0 & rhs.0)
+    }
+}
+
+impl FromIterator<u8> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut set = NumberSet::default();
+        for number in iter {
+            set.insert(number);
+        }
+        set
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning_numbers: NumberSet,
+    pub available_numbers: NumberSet,
+}
+
+impl Card {
+    /// How many of the winning numbers the card has.
+    pub fn num_matches(&self) -> u32 {
+        (self.winning_numbers & self.available_numbers).len()
+    }
+
+    /// One point for the first match, doubled for every match after it. A
+    /// card can have up to 128 matches, so the score is a `u128`.
+    pub fn score(&self) -> u128 {
+        match self.num_matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+/// Parses every card and checks the pile as a whole: ids have to be unique
+/// with no gaps, no side may repeat a number and every card needs as many
+/// winning numbers as the first. All problems are reported together as
+/// [`AocError::InvalidCards`].
+pub fn parse_cards(input: &str) -> miette::Result<Vec<Card>, AocError> {
+    let mut cards = vec![];
+    let mut problems = vec![];
+    let mut first_with_id: HashMap<u32, &str> = HashMap::new();
+    let mut winning_count = None;
+    for raw_line in input.split_inclusive('\n') {
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+        let span = |part: &str| (input.offset(part), part.len()).into();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Ok((rest, raw)) = card_with_spans(line) else {
+            problems.push(CardProblem::NotACard { span: span(line) });
+            continue;
+        };
+        if !rest.is_empty() {
+            problems.push(CardProblem::NotACard { span: span(rest) });
+        }
+        match first_with_id.entry(raw.id.1) {
+            Entry::Occupied(first) => problems.push(CardProblem::DuplicateId {
+                id: raw.id.1,
+                first: span(first.get()),
+                span: span(raw.id.0),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(raw.id.0);
+            }
+        }
+        for side in [&raw.winning_numbers, &raw.available_numbers] {
+            let mut seen: HashMap<u8, &str> = HashMap::new();
+            for &(text, number) in side {
+                if let Some(first) = seen.insert(number, text) {
+                    problems.push(CardProblem::DuplicateNumber {
+                        number,
+                        first: span(first),
+                        span: span(text),
+                    });
+                }
+            }
+        }
+        match winning_count {
+            None => winning_count = Some(raw.winning_numbers.len()),
+            Some(expected) if expected != raw.winning_numbers.len() => {
+                problems.push(CardProblem::WinningCountMismatch {
+                    expected,
+                    found: raw.winning_numbers.len(),
+                    span: span(raw.winning_text),
+                })
+            }
+            Some(_) => {}
+        }
+        cards.push(raw.card());
+    }
+
+    let mut ids: Vec<(u32, &str)> = first_with_id.into_iter().collect();
+    ids.sort_unstable();
+    for pair in ids.windows(2) {
+        let [(previous, _), (id, text)] = pair else {
+            continue;
+        };
+        if id - previous > 1 {
+            problems.push(CardProblem::MissingIds {
+                from: previous + 1,
+                to: id - 1,
+                span: (input.offset(text), text.len()).into(),
+            });
+        }
+    }
+
+    if cards.is_empty() && problems.is_empty() {
+        return Err(AocError::ParseError("there are no cards".to_string()));
+    }
+    if problems.is_empty() {
+        Ok(cards)
+    } else {
+        Err(AocError::InvalidCards {
+            src: input.to_string(),
+            problems,
+        })
+    }
+}
+
+/// A single card, without checks that need the rest of the pile.
+pub fn card(input: &str) -> IResult<&str, Card> {
+    let (input, raw) = card_with_spans(input)?;
+    Ok((input, raw.card()))
+}
+
+/// A card that keeps the text of its id and numbers, to point diagnostics at.
+struct RawCard<'a> {
+    id: (&'a str, u32),
+    winning_text: &'a str,
+    winning_numbers: Vec<(&'a str, u8)>,
+    available_numbers: Vec<(&'a str, u8)>,
+}
+
+impl RawCard<'_> {
+    fn card(&self) -> Card {
+        Card {
+            id: self.id.1,
+            winning_numbers: self.winning_numbers.iter().map(|&(_, n)| n).collect(),
+            available_numbers: self.available_numbers.iter().map(|&(_, n)| n).collect(),
+        }
+    }
+}
+
+fn card_with_spans(input: &str) -> IResult<&str, RawCard<'_>> {
+    let (input, id) = delimited(
+        tuple((tag("Card"), space1)),
+        consumed(u32),
+        tuple((tag(":"), space0)),
+    )(input)?;
+    let (input, ((winning_text, winning_numbers), available_numbers)) = separated_pair(
+        consumed(numbers),
+        delimited(space0, tag("|"), space0),
+        numbers,
+    )(input)?;
+
+    Ok((
+        input,
+        RawCard {
+            id,
+            winning_text,
+            winning_numbers,
+            available_numbers,
+        },
+    ))
+}
+
+fn numbers(input: &str) -> IResult<&str, Vec<(&str, u8)>> {
+    separated_list1(space1, consumed(verify(u8, |n| *n <= NumberSet::MAX)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card() -> miette::Result<()> {
+        let cards = parse_cards(include_str!("../test-input.txt"))?;
+        assert_eq!(
+            vec![4, 2, 2, 1, 0, 0],
+            cards.iter().map(Card::num_matches).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![17, 48, 83, 86],
+            (cards[0].winning_numbers & cards[0].available_numbers)
+                .iter()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(8, cards[0].score());
+        assert_eq!(0, cards[5].score());
+        let all: NumberSet = (0..128).collect();
+        let card = Card {
+            id: 1,
+            winning_numbers: all,
+            available_numbers: all,
+        };
+        assert_eq!(1 << 127, card.score());
+
+        let set: NumberSet = [0, 127, 64].into_iter().collect();
+        assert_eq!(3, set.len());
+        assert!(set.contains(127) && !set.contains(128));
+        assert!(parse_cards("Card 1: 128 | 1").is_err());
+        Ok(())
+    }
+
+    fn problems(input: &str) -> Vec<CardProblem> {
+        match parse_cards(input) {
+            Err(AocError::InvalidCards { problems, .. }) => problems,
+            other => panic!("expected problems, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validation() -> miette::Result<()> {
+        let cards = parse_cards(include_str!("../input1.txt"))?;
+        assert_eq!(
+            (1..=212).collect::<Vec<_>>(),
+            cards.iter().map(|c| c.id).collect::<Vec<_>>()
+        );
+
+        let input = "Card 1: 1 2 | 3\nCard 4: 1 1 | 2 2\nCard 1: 5 | 6\nCard 2: 1 2 | 3 x";
+        assert_eq!(
+            vec![
+                CardProblem::DuplicateNumber {
+                    number: 1,
+                    first: (24, 1).into(),
+                    span: (26, 1).into(),
+                },
+                CardProblem::DuplicateNumber {
+                    number: 2,
+                    first: (30, 1).into(),
+                    span: (32, 1).into(),
+                },
+                CardProblem::DuplicateId {
+                    id: 1,
+                    first: (5, 1).into(),
+                    span: (39, 1).into(),
+                },
+                CardProblem::WinningCountMismatch {
+                    expected: 2,
+                    found: 1,
+                    span: (42, 1).into(),
+                },
+                CardProblem::NotACard {
+                    span: (63, 2).into(),
+                },
+                CardProblem::MissingIds {
+                    from: 3,
+                    to: 3,
+                    span: (21, 1).into(),
+                },
+            ],
+            problems(input)
+        );
+        Ok(())
+    }
+}
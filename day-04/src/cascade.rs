@@ -0,0 +1,184 @@
+//! Card by card breakdown of part 2, to audit where every copy came from.
+use std::{collections::HashMap, fmt::Write};
+
+use serde::Serialize;
+
+use crate::{card::Card, custom_error::AocError};
+
+/// Copies of one card won from an earlier card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CopiesFrom {
+    pub card: u32,
+    pub copies: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardTrace {
+    pub id: u32,
+    pub num_matches: u32,
+    pub score: u128,
+    /// Copies won from earlier cards, not counting the original.
+    pub copies: usize,
+    pub copies_from: Vec<CopiesFrom>,
+}
+
+impl CardTrace {
+    /// The original and every copy, `None` if there are more than a `usize` holds.
+    pub fn instances(&self) -> Option<usize> {
+        self.copies.checked_add(1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Cascade {
+    pub cards: Vec<CardTrace>,
+    pub total_cards: usize,
+}
+
+/// Each instance of card `id` with `n` matches wins one copy of each of
+/// cards `id + 1` to `id + n`, whatever order they were listed in. Copies of
+/// cards that don't exist are dropped. The trace is in card id order.
+///
+/// Copies double with every card that wins them, so a long enough pile of
+/// winning cards fails with [`AocError::Overflow`].
+pub fn cascade(cards: &[Card]) -> miette::Result<Cascade, AocError> {
+    let overflow = || AocError::Overflow("number of cards");
+    let mut sorted = cards.to_vec();
+    sorted.sort_by_key(|card| card.id);
+    let index_of: HashMap<u32, usize> = sorted
+        .iter()
+        .enumerate()
+        .map(|(index, card)| (card.id, index))
+        .collect();
+    let mut traces: Vec<CardTrace> = sorted
+        .iter()
+        .map(|card| CardTrace {
+            id: card.id,
+            num_matches: card.num_matches(),
+            score: card.score(),
+            copies: 0,
+            copies_from: vec![],
+        })
+        .collect();
+
+    for index in 0..traces.len() {
+        let (id, instances, num_matches) = {
+            let trace = &traces[index];
+            (
+                trace.id,
+                trace.instances().ok_or_else(overflow)?,
+                trace.num_matches,
+            )
+        };
+        // ids past u32::MAX can't exist, so those copies are dropped too
+        for won_id in (1..=num_matches).map_while(|offset| id.checked_add(offset)) {
+            let Some(&won_index) = index_of.get(&won_id) else {
+                continue;
+            };
+            let won = &mut traces[won_index];
+            won.copies = won.copies.checked_add(instances).ok_or_else(overflow)?;
+            won.copies_from.push(CopiesFrom {
+                card: id,
+                copies: instances,
+            });
+        }
+    }
+
+    let total_cards = traces
+        .iter()
+        .try_fold(0usize, |total, trace| total.checked_add(trace.instances()?))
+        .ok_or_else(overflow)?;
+    Ok(Cascade {
+        total_cards,
+        cards: traces,
+    })
+}
+
+impl Cascade {
+    /// One row per card, with `copies_from` as `card:copies` pairs separated by spaces.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("id,num_matches,score,copies,copies_from\n");
+        for trace in &self.cards {
+            let copies_from = trace
+                .copies_from
+                .iter()
+                .map(|from| format!("{}:{}", from.card, from.copies))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{}",
+                trace.id, trace.num_matches, trace.score, trace.copies, copies_from
+            );
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> miette::Result<String, AocError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+
+    #[test]
+    fn test_cascade_at_the_last_id() -> miette::Result<()> {
+        for input in ["Card 4294967295: 1 | 2", "Card 4294967295: 1 | 1"] {
+            let cards = parse_cards(input)?;
+            assert_eq!(1, cascade(&cards)?.total_cards);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cascade_overflow() -> miette::Result<()> {
+        // card n has 2^(n-1) instances, far past a usize by card 80
+        let numbers: String = (0..128).map(|n| format!(" {n}")).collect();
+        let input: String = (1..=80)
+            .map(|id| format!("Card {id}:{numbers} |{numbers}\n"))
+            .collect();
+        assert!(matches!(
+            cascade(&parse_cards(&input)?),
+            Err(AocError::Overflow("number of cards"))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cascade() -> miette::Result<()> {
+        let cards = parse_cards(include_str!("../test-input.txt"))?;
+        let cascade = cascade(&cards)?;
+        assert_eq!(30, cascade.total_cards);
+        assert_eq!(
+            vec![Some(1), Some(2), Some(4), Some(8), Some(14), Some(1)],
+            cascade
+                .cards
+                .iter()
+                .map(CardTrace::instances)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                CopiesFrom { card: 1, copies: 1 },
+                CopiesFrom { card: 3, copies: 4 },
+                CopiesFrom { card: 4, copies: 8 },
+            ],
+            cascade.cards[4].copies_from
+        );
+
+        let csv = cascade.to_csv();
+        assert_eq!(Some("5,0,0,13,1:1 3:4 4:8"), csv.lines().nth(5));
+        // shuffling the cards doesn't change which cards win copies
+        let mut shuffled = cards.clone();
+        shuffled.reverse();
+        assert_eq!(cascade, super::cascade(&shuffled)?);
+
+        let json: serde_json::Value = serde_json::from_str(&cascade.to_json()?).unwrap();
+        assert_eq!(8, json["cards"][0]["score"]);
+        assert_eq!(30, json["total_cards"]);
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
@@ -9,5 +9,74 @@ pub enum AocError {
 
     #[error("Parse error")]
     #[diagnostic(code(aoc::parse_error))]
-    ParseError(String)
+    ParseError(String),
+
+    #[error(transparent)]
+    #[diagnostic(code(aoc::json_error))]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("the {0} doesn't fit in the result")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow(&'static str),
+
+    #[error("found {} problems in the cards", problems.len())]
+    #[diagnostic(code(aoc::invalid_cards))]
+    InvalidCards {
+        #[source_code]
+        src: String,
+        #[related]
+        problems: Vec<CardProblem>,
+    },
+}
+
+/// Something wrong with the pile of cards, pointing at where in the input it is.
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq, Eq)]
+pub enum CardProblem {
+    #[error("there are two cards numbered {id}")]
+    #[diagnostic(code(aoc::duplicate_id))]
+    DuplicateId {
+        id: u32,
+        #[label("first card {id}")]
+        first: SourceSpan,
+        #[label("second card {id}")]
+        span: SourceSpan,
+    },
+
+    #[error("cards {from} to {to} are missing")]
+    #[diagnostic(code(aoc::missing_ids))]
+    MissingIds {
+        from: u32,
+        to: u32,
+        #[label("the next card after the gap")]
+        span: SourceSpan,
+    },
+
+    #[error("{number} appears twice on the same side of a card")]
+    #[diagnostic(code(aoc::duplicate_number))]
+    DuplicateNumber {
+        number: u8,
+        #[label("first here")]
+        first: SourceSpan,
+        #[label("and again here")]
+        span: SourceSpan,
+    },
+
+    #[error("card has {found} winning numbers instead of {expected}")]
+    #[diagnostic(
+        code(aoc::winning_count_mismatch),
+        help("every card needs as many winning numbers as the first")
+    )]
+    WinningCountMismatch {
+        expected: usize,
+        found: usize,
+        #[label("{found} winning numbers")]
+        span: SourceSpan,
+    },
+
+    #[error("expected `Card N: winning numbers | numbers you have`")]
+    #[diagnostic(code(aoc::not_a_card))]
+    NotACard {
+        #[label("not part of a card")]
+        span: SourceSpan,
+    },
 }
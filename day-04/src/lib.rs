@@ -1,3 +1,8 @@
+pub mod card;
+pub mod cascade;
 pub mod custom_error;
 pub mod part1;
 pub mod part2;
+pub mod stream;
+#[cfg(feature = "no-alloc")]
+pub mod no_alloc;
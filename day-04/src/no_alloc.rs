@@ -0,0 +1,170 @@
+//! Card solvers that never allocate: they read the input bytes directly,
+//! keep each side of a card as a `u128` bitset and track the copies won in
+//! part 2 in a fixed ring buffer, so memory use doesn't grow with the pile.
+use core::fmt;
+
+/// Where reading the pile stopped, as a byte offset into the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoAllocError {
+    /// A line without the `:` that ends `Card N:`.
+    MissingColon { offset: usize },
+    /// Something other than a number or a space between `:`, `|` and the
+    /// end of the line.
+    UnexpectedByte { offset: usize, byte: u8 },
+    /// A card number that doesn't fit in the 128-bit set.
+    NumberTooLarge { offset: usize },
+    /// The total score is more than a `u128` holds.
+    ScoreOverflow,
+    /// More copies of the cards than a `usize` holds.
+    CountOverflow,
+}
+
+impl fmt::Display for NoAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoAllocError::MissingColon { offset } => {
+                write!(f, "card starting at byte {offset} has no `:`")
+            }
+            NoAllocError::UnexpectedByte { offset, byte } => {
+                write!(f, "unexpected {:?} at byte {offset}", char::from(*byte))
+            }
+            NoAllocError::NumberTooLarge { offset } => {
+                write!(f, "card number at byte {offset} is larger than 127")
+            }
+            NoAllocError::ScoreOverflow => write!(f, "total score doesn't fit in a u128"),
+            NoAllocError::CountOverflow => write!(f, "number of cards doesn't fit in a usize"),
+        }
+    }
+}
+
+/// Longest run of cards a single card can win copies of.
+const MAX_MATCHES: usize = 128;
+
+pub fn part1(input: &[u8]) -> Result<u128, NoAllocError> {
+    let mut score = Some(0u128);
+    for_each_card(input, |matches| {
+        if matches > 0 {
+            score = score.and_then(|score| score.checked_add(1 << (matches - 1)));
+        }
+    })?;
+    score.ok_or(NoAllocError::ScoreOverflow)
+}
+
+pub fn part2(input: &[u8]) -> Result<usize, NoAllocError> {
+    // pending[i] holds the extra copies won for the card `i` positions ahead
+    let mut pending = [0usize; MAX_MATCHES];
+    let mut head = 0;
+    let mut total = Some(0usize);
+    for_each_card(input, |matches| {
+        let Some(sum) = total else { return };
+        let copies = core::mem::take(&mut pending[head]).checked_add(1);
+        head = (head + 1) % MAX_MATCHES;
+        total = copies.and_then(|copies| {
+            for i in 0..matches as usize {
+                let won = &mut pending[(head + i) % MAX_MATCHES];
+                *won = won.checked_add(copies)?;
+            }
+            sum.checked_add(copies)
+        });
+    })?;
+    total.ok_or(NoAllocError::CountOverflow)
+}
+
+fn for_each_card(input: &[u8], mut on_card: impl FnMut(u32)) -> Result<(), NoAllocError> {
+    let mut offset = 0;
+    while offset < input.len() {
+        // skip the `Card N:` prefix
+        let card_start = offset;
+        while offset < input.len() && input[offset] != b':' {
+            offset += 1;
+        }
+        if offset == input.len() {
+            return Err(NoAllocError::MissingColon { offset: card_start });
+        }
+        offset += 1;
+
+        let (winning, after_winning) = read_set(input, offset, b'|')?;
+        if input.get(after_winning) != Some(&b'|') {
+            return Err(NoAllocError::UnexpectedByte {
+                offset: after_winning,
+                byte: input.get(after_winning).copied().unwrap_or(b'\n'),
+            });
+        }
+        let (available, after_available) = read_set(input, after_winning + 1, b'\n')?;
+        on_card((winning & available).count_ones());
+        offset = after_available + 1;
+    }
+    Ok(())
+}
+
+/// Reads space separated numbers into a bitset until `end` or the end of input.
+fn read_set(input: &[u8], mut offset: usize, end: u8) -> Result<(u128, usize), NoAllocError> {
+    let mut set = 0u128;
+    while let Some(&byte) = input.get(offset) {
+        match byte {
+            b' ' | b'\r' => offset += 1,
+            b'0'..=b'9' => {
+                let start = offset;
+                let mut number = 0u32;
+                while let Some(digit @ b'0'..=b'9') = input.get(offset) {
+                    number = number * 10 + u32::from(digit - b'0');
+                    offset += 1;
+                }
+                if number >= 128 {
+                    return Err(NoAllocError::NumberTooLarge { offset: start });
+                }
+                set |= 1 << number;
+            }
+            b if b == end => break,
+            byte => return Err(NoAllocError::UnexpectedByte { offset, byte }),
+        }
+    }
+    Ok((set, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        for input in [
+            include_str!("../test-input.txt"),
+            include_str!("../input1.txt"),
+        ] {
+            assert_eq!(
+                crate::part1::process(input)?,
+                part1(input.as_bytes()).unwrap()
+            );
+            assert_eq!(
+                crate::part2::process(input)?,
+                part2(input.as_bytes()).unwrap()
+            );
+        }
+        assert_eq!(
+            Err(NoAllocError::NumberTooLarge { offset: 8 }),
+            part1(b"Card 1: 128 | 1")
+        );
+        assert_eq!(
+            Err(NoAllocError::UnexpectedByte {
+                offset: 10,
+                byte: b'\n'
+            }),
+            part1(b"Card 1: 1 \nCard 2: 1 | 1")
+        );
+
+        // 64 matches score 2^63, so two such cards are past a u64
+        let numbers: String = (0..64).map(|n| format!(" {n}")).collect();
+        let card = format!("Card 1:{numbers} |{numbers}\n");
+        assert_eq!(Ok(1 << 64), part1(card.repeat(2).as_bytes()));
+
+        // copies double with every card that wins all of the next ones
+        let numbers: String = (0..128).map(|n| format!(" {n}")).collect();
+        let card = format!("Card 1:{numbers} |{numbers}\n");
+        assert_eq!(
+            Err(NoAllocError::CountOverflow),
+            part2(card.repeat(80).as_bytes())
+        );
+        Ok(())
+    }
+}
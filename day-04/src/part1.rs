@@ -1,56 +1,17 @@
-use std::collections::HashMap;
-
-use nom::{
-    bytes::complete::{tag, take_until1},
-    character::complete::{newline, space0, space1},
-    multi::separated_list1,
-    sequence::{delimited, separated_pair},
-    IResult,
+use crate::{
+    card::{parse_cards, Card},
+    custom_error::AocError,
 };
 
-use crate::custom_error::AocError;
-
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let (_, game_list) = parse_input(input).map_err(|e| AocError::ParseError(e.to_string()))?;
+pub fn process(input: &str) -> miette::Result<u128, AocError> {
+    let cards = parse_cards(input)?;
 
-    // dbg!(&game_list, &game_list.len());
-    let sum_of_scores = game_list
+    cards
         .iter()
-        .fold(0, |score, (winning_numbers, available_numbers)| {
-            let mut game_score = 0;
-            for num in winning_numbers {
-                if available_numbers.contains_key(num) {
-                    if game_score == 0 {
-                        game_score = 1
-                    } else {
-                        game_score *= 2
-                    }
-                }
-            }
-            score + game_score
-        });
-
-    Ok(sum_of_scores)
-}
-
-pub fn parse_input(input: &str) -> IResult<&str, Vec<(Vec<u8>, HashMap<u8, ()>)>> {
-    separated_list1(newline, line)(input)
-}
-
-pub fn line(input: &str) -> IResult<&str, (Vec<u8>, HashMap<u8, ()>)> {
-    let (input, _) = take_until1(": ")(input)?;
-    let (input, _) = tag(":")(input)?;
-    let (input, _) = space0(input)?;
-    let (input, (winning_numbers, game_numbers)) = separated_pair(
-        separated_list1(space1, nom::character::complete::u8),
-        delimited(space0, tag("|"), space0),
-        nom::combinator::map(separated_list1(space1, nom::character::complete::u8), |v| {
-            v.iter().map(|n| (*n, ())).collect::<HashMap<u8, ()>>()
-        }),
-    )(input)?;
-
-    Ok((input, (winning_numbers, game_numbers)))
+        .map(Card::score)
+        .try_fold(0u128, u128::checked_add)
+        .ok_or(AocError::Overflow("total score"))
 }
 
 #[cfg(test)]
@@ -61,6 +22,14 @@ mod tests {
     fn test_process() -> miette::Result<()> {
         let input = include_str!("../test-input.txt");
         assert_eq!(13, process(input)?);
+
+        // two cards scoring 2^127 each
+        let numbers: String = (0..128).map(|n| format!(" {n}")).collect();
+        let input = format!("Card 1:{numbers} |{numbers}\nCard 2:{numbers} |{numbers}");
+        assert!(matches!(
+            process(&input),
+            Err(AocError::Overflow("total score"))
+        ));
         Ok(())
     }
 }
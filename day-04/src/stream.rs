@@ -0,0 +1,79 @@
+//! Card solvers over any [`BufRead`], one card per line. Only the copies
+//! still owed to upcoming cards are kept between lines.
+use std::{collections::VecDeque, io::BufRead};
+
+use line_stream::for_each_line;
+use nom::combinator::all_consuming;
+
+use crate::{card, custom_error::AocError};
+
+/// A single card can score up to 2^127, so scores are summed as checked `u128`.
+pub fn part1<R: BufRead>(reader: R) -> miette::Result<u128, AocError> {
+    let mut score = 0u128;
+    for_each_card(reader, |num_matches| {
+        if num_matches > 0 {
+            score = score
+                .checked_add(1 << (num_matches - 1))
+                .ok_or(AocError::Overflow("total score"))?;
+        }
+        Ok(())
+    })?;
+    Ok(score)
+}
+
+/// Memory is bounded by the largest number of matches on a single card. The
+/// number of copies can grow exponentially, so it is counted as checked `u64`.
+pub fn part2<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    let mut pending_copies: VecDeque<u64> = VecDeque::new();
+    let mut num_cards = 0u64;
+    for_each_card(reader, |num_matches| {
+        let overflow = || AocError::Overflow("number of cards");
+        let current_count = pending_copies
+            .pop_front()
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or_else(overflow)?;
+        num_cards = num_cards.checked_add(current_count).ok_or_else(overflow)?;
+
+        if pending_copies.len() < num_matches {
+            pending_copies.resize(num_matches, 0);
+        }
+        for count in pending_copies.iter_mut().take(num_matches) {
+            *count = count.checked_add(current_count).ok_or_else(overflow)?;
+        }
+        Ok(())
+    })?;
+    Ok(num_cards)
+}
+
+fn for_each_card<R: BufRead>(
+    reader: R,
+    mut on_card: impl FnMut(usize) -> miette::Result<(), AocError>,
+) -> miette::Result<(), AocError> {
+    for_each_line(reader, |line_number, line| {
+        let (_, card) = all_consuming(card::card)(line)
+            .map_err(|e| AocError::ParseError(format!("line {line_number}: {e}")))?;
+        on_card(card.num_matches() as usize)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(crate::part1::process(input)?, part1(input.as_bytes())?);
+        assert_eq!(
+            crate::part2::process(input)? as u64,
+            part2(input.as_bytes())?
+        );
+
+        // 64 matches score 2^63, so two such cards are past a u64
+        let numbers: String = (0..64).map(|n| format!(" {n}")).collect();
+        let card = format!("Card 1:{numbers} |{numbers}\n");
+        assert_eq!(1 << 64, part1(card.repeat(2).as_bytes())?);
+        Ok(())
+    }
+}
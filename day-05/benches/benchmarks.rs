@@ -19,4 +19,9 @@ fn part2() {
         "../input2.txt",
     )))
     .unwrap();
-}
\ No newline at end of file
+}
+
+#[divan::bench]
+fn part2_by_location() {
+    part2::process_by_location(divan::black_box(include_str!("../input2.txt"))).unwrap();
+}
@@ -14,4 +14,26 @@ pub enum AocError {
     #[error("The result was empty")]
     #[diagnostic(code(aoc::empty_result))]
     EmptyResult,
+
+    #[error("the range in `{0}` ends past the largest almanac value")]
+    #[diagnostic(code(aoc::range_overflow))]
+    RangeOverflow(String),
+
+    #[error("seed {0} has no range length")]
+    #[diagnostic(
+        code(aoc::unpaired_seed),
+        help("part 2 reads the seeds as `start length` pairs")
+    )]
+    UnpairedSeed(u64),
+
+    #[error("{0}")]
+    #[diagnostic(
+        code(aoc::broken_chain),
+        help("every category needs exactly one map leading on from it")
+    )]
+    BrokenChain(String),
+
+    #[error("no map leads to `{0}`")]
+    #[diagnostic(code(aoc::unknown_category))]
+    UnknownCategory(String),
 }
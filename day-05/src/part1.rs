@@ -3,8 +3,9 @@ use std::ops::Range;
 use nom::{
     branch::permutation,
     bytes::complete::tag,
-    character::complete::{alpha1, multispace1, newline, space1, u32},
+    character::complete::{alpha1, multispace1, newline, space1, u64},
     combinator::opt,
+    error::ErrorKind,
     multi::separated_list1,
     sequence::{preceded, separated_pair},
     IResult,
@@ -13,63 +14,185 @@ use nom::{
 use crate::custom_error::AocError;
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let (_, almanac) = parse_input(input).map_err(|e| AocError::NomParseError(e.to_string()))?;
-
-    let min_destination = almanac.seeds.iter().map(|seed| {
-        almanac.maps.iter().fold(*seed, |current_val, map| {
-            let Some(matching_map) = map.iter().find(|m| m.start.contains(&current_val)) else {
-                return current_val;
-            };
-            let offset = current_val - matching_map.start.start;
-            matching_map.destination.start + offset
+pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    let almanac = parse_almanac(input)?;
+
+    let min_destination = almanac
+        .seeds
+        .iter()
+        .map(|seed| {
+            almanac
+                .maps
+                .iter()
+                .fold(*seed, |current_val, map| map.convert(current_val))
         })
-    }).min().ok_or(AocError::EmptyResult)?;
-    assert_ne!(min_destination, u32::MAX);
-    dbg!(u32::MAX);
-   Ok(min_destination)
+        .min()
+        .ok_or(AocError::EmptyResult)?;
+    Ok(min_destination)
+}
+
+/// Parses the whole almanac and puts the maps in chain order from `seed` to
+/// `location`. A map whose ranges don't fit in a `u64` is reported as
+/// [`AocError::RangeOverflow`].
+pub fn parse_almanac(input: &str) -> miette::Result<Almanac, AocError> {
+    let (_, almanac) = parse_input(input).map_err(|e| match e {
+        nom::Err::Failure(e) if e.code == ErrorKind::TooLarge => {
+            AocError::RangeOverflow(e.input.lines().next().unwrap_or_default().to_string())
+        }
+        e => AocError::NomParseError(e.to_string()),
+    })?;
+    Ok(Almanac {
+        maps: chain(almanac.maps, "seed", "location")?,
+        ..almanac
+    })
+}
+
+/// Orders the maps so each one's destination is the next one's source,
+/// from `first` to `last`.
+pub fn chain(
+    mut maps: Vec<CategoryMap>,
+    first: &str,
+    last: &str,
+) -> miette::Result<Vec<CategoryMap>, AocError> {
+    let mut chained = Vec::with_capacity(maps.len());
+    let mut category = first.to_string();
+    while category != last {
+        let from_category: Vec<usize> = (0..maps.len())
+            .filter(|&i| maps[i].source == category)
+            .collect();
+        let [index] = from_category[..] else {
+            return Err(AocError::BrokenChain(if from_category.is_empty() {
+                format!("there is no map from `{category}`, so nothing reaches `{last}`")
+            } else {
+                format!("there are {} maps from `{category}`", from_category.len())
+            }));
+        };
+        let next = maps.swap_remove(index);
+        category.clone_from(&next.destination);
+        chained.push(next);
+    }
+    if !maps.is_empty() {
+        let unused = maps
+            .iter()
+            .map(|m| format!("{}-to-{}", m.source, m.destination))
+            .collect::<Vec<_>>();
+        return Err(AocError::BrokenChain(format!(
+            "maps not on the way from `{first}` to `{last}`: {}",
+            unused.join(", ")
+        )));
+    }
+    Ok(chained)
 }
 
 pub fn parse_input(input: &str) -> IResult<&str, Almanac> {
     let (input, _) = tag("seeds: ")(input)?;
-    let (input, seeds) = separated_list1(space1, u32)(input)?;
+    let (input, seeds) = separated_list1(space1, u64)(input)?;
     let (input, _) = multispace1(input)?;
-    let (input, maps) = separated_list1(permutation((newline, opt(newline))), parse_map_list)(input)?;
-    Ok((input, Almanac {seeds, maps}))
+    let (input, maps) =
+        separated_list1(permutation((newline, opt(newline))), parse_map_list)(input)?;
+    Ok((input, Almanac { seeds, maps }))
 }
 
-pub fn parse_map_list(input: &str) -> IResult<&str, Vec<AlmanacMap>> {
-    let (input, _) = separated_pair(alpha1, tag("-to-"), alpha1)(input)?;
+pub fn parse_map_list(input: &str) -> IResult<&str, CategoryMap> {
+    let (input, (source, destination)) = separated_pair(alpha1, tag("-to-"), alpha1)(input)?;
     let (input, _) = preceded(space1, tag("map:"))(input)?;
     let (input, _) = newline(input)?;
 
-    let (input, map) = separated_list1(newline, parse_map)(input)?;
+    let (input, ranges) = separated_list1(newline, parse_map)(input)?;
 
-    Ok((input, map))
+    Ok((
+        input,
+        CategoryMap {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            ranges,
+        },
+    ))
 }
 
+/// Fails outright, rather than backtracking, if either range would end past `u64::MAX`.
 pub fn parse_map(input: &str) -> IResult<&str, AlmanacMap> {
-    let (input, destination_start) = u32(input)?;
+    let line = input;
+    let (input, destination_start) = u64(input)?;
     let (input, _) = space1(input)?;
-    let (input, source_start) = u32(input)?;
+    let (input, source_start) = u64(input)?;
     let (input, _) = space1(input)?;
-    let (input, map_size) = u32(input)?;
+    let (input, map_size) = u64(input)?;
 
-    let almanac_map = AlmanacMap {start: source_start..(source_start + map_size), destination: destination_start..(destination_start + map_size) };
+    let almanac_map = AlmanacMap::new(destination_start, source_start, map_size).ok_or(
+        nom::Err::Failure(nom::error::Error::new(line, ErrorKind::TooLarge)),
+    )?;
 
-    Ok((input, almanac_map)) 
+    Ok((input, almanac_map))
 }
 
 #[derive(Debug, Default)]
 pub struct Almanac {
-    pub seeds: Vec<u32>,
-    pub maps: Vec<Vec<AlmanacMap>>,
+    pub seeds: Vec<u64>,
+    pub maps: Vec<CategoryMap>,
 }
 
-#[derive(Debug, Default)]
+impl Almanac {
+    /// Follows `value` of the first map's source category as far as `category`,
+    /// e.g. the humidity for a seed.
+    pub fn lookup(&self, value: u64, category: &str) -> miette::Result<u64, AocError> {
+        let mut current_val = value;
+        if self.maps.first().is_some_and(|m| m.source == category) {
+            return Ok(current_val);
+        }
+        for map in &self.maps {
+            current_val = map.convert(current_val);
+            if map.destination == category {
+                return Ok(current_val);
+            }
+        }
+        Err(AocError::UnknownCategory(category.to_string()))
+    }
+
+    /// The source category of the first map, then each map's destination.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.maps
+            .first()
+            .map(|m| m.source.as_str())
+            .into_iter()
+            .chain(self.maps.iter().map(|m| m.destination.as_str()))
+    }
+}
+
+/// One `source-to-destination map:` block.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CategoryMap {
+    pub source: String,
+    pub destination: String,
+    pub ranges: Vec<AlmanacMap>,
+}
+
+impl CategoryMap {
+    /// Values no range covers map to themselves.
+    pub fn convert(&self, value: u64) -> u64 {
+        match self.ranges.iter().find(|m| m.start.contains(&value)) {
+            Some(matching_map) => {
+                matching_map.destination.start + (value - matching_map.start.start)
+            }
+            None => value,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct AlmanacMap {
-    pub destination: Range<u32>,
-    pub start: Range<u32>,
+    pub destination: Range<u64>,
+    pub start: Range<u64>,
+}
+
+impl AlmanacMap {
+    /// `None` if either range would end past `u64::MAX`.
+    pub fn new(destination_start: u64, source_start: u64, size: u64) -> Option<Self> {
+        Some(AlmanacMap {
+            start: source_start..source_start.checked_add(size)?,
+            destination: destination_start..destination_start.checked_add(size)?,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -82,4 +205,74 @@ mod tests {
         assert_eq!(35, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_large_values() -> miette::Result<()> {
+        // the real input has ranges ending at exactly 2^32
+        let input = include_str!("../input1.txt");
+        assert!(process(input)? > 0);
+
+        let input =
+            "seeds: 18446744073709551610\n\nseed-to-location map:\n0 18446744073709551600 15";
+        assert_eq!(10, process(input)?);
+
+        let input = "seeds: 1\n\nseed-to-location map:\n0 18446744073709551600 16\n5 0 2";
+        assert!(matches!(
+            process(input),
+            Err(AocError::RangeOverflow(line)) if line == "0 18446744073709551600 16"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup() -> miette::Result<()> {
+        let almanac = parse_almanac(include_str!("../test-input.txt"))?;
+        assert_eq!(
+            vec![
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ],
+            almanac.categories().collect::<Vec<_>>()
+        );
+        assert_eq!(78, almanac.lookup(79, "humidity")?);
+        assert_eq!(81, almanac.lookup(79, "soil")?);
+        assert_eq!(82, almanac.lookup(79, "location")?);
+        assert_eq!(79, almanac.lookup(79, "seed")?);
+        assert!(matches!(
+            almanac.lookup(79, "gravel"),
+            Err(AocError::UnknownCategory(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_chain_order() -> miette::Result<()> {
+        let input = include_str!("../test-input.txt");
+        let (seeds, maps) = input.split_once("\n\n").unwrap();
+        let mut blocks: Vec<&str> = maps.split("\n\n").map(str::trim_end).collect();
+        blocks.reverse();
+        let shuffled = format!("{seeds}\n\n{}", blocks.join("\n\n"));
+        assert_eq!(35, process(&shuffled)?);
+
+        let without_water = blocks
+            .iter()
+            .filter(|block| !block.starts_with("water"))
+            .copied()
+            .collect::<Vec<_>>();
+        let broken = format!("{seeds}\n\n{}", without_water.join("\n\n"));
+        assert!(matches!(
+            process(&broken),
+            Err(AocError::BrokenChain(message)) if message.contains("`water`")
+        ));
+
+        let extra = format!("{}\n\nsoil-to-gravel map:\n1 2 3", input.trim_end());
+        assert!(matches!(process(&extra), Err(AocError::BrokenChain(_))));
+        Ok(())
+    }
 }
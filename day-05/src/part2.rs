@@ -1,8 +1,84 @@
-use crate::custom_error::AocError;
+use std::ops::Range;
 
+use crate::{
+    custom_error::AocError,
+    part1::{parse_almanac, AlmanacMap},
+    piecewise::Piecewise,
+};
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    let almanac = parse_almanac(input)?;
+
+    let locations = almanac
+        .maps
+        .iter()
+        .fold(seed_ranges(&almanac.seeds)?, |ranges, layer| {
+            map_ranges(&ranges, &layer.ranges)
+        });
+    let min_location = locations
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .ok_or(AocError::EmptyResult)?;
+    Ok(min_location)
+}
+
+/// Composes the maps into one function and searches its segments from the
+/// lowest location up, instead of pushing the seed ranges through every layer.
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    todo!("day 01 - part 1");
+pub fn process_by_location(input: &str) -> miette::Result<u64, AocError> {
+    let almanac = parse_almanac(input)?;
+    let seed_to_location = Piecewise::from_almanac(&almanac);
+    seed_to_location
+        .min_over(&seed_ranges(&almanac.seeds)?)
+        .ok_or(AocError::EmptyResult)
+}
+
+/// The seeds line read as `start length` pairs.
+pub fn seed_ranges(seeds: &[u64]) -> miette::Result<Vec<Range<u64>>, AocError> {
+    let pairs = seeds.chunks_exact(2);
+    if let Some(&unpaired) = pairs.remainder().first() {
+        return Err(AocError::UnpairedSeed(unpaired));
+    }
+    let mut ranges = Vec::with_capacity(seeds.len() / 2);
+    for pair in pairs {
+        let end = pair[0]
+            .checked_add(pair[1])
+            .ok_or_else(|| AocError::RangeOverflow(format!("seeds: {} {}", pair[0], pair[1])))?;
+        if pair[0] < end {
+            ranges.push(pair[0]..end);
+        }
+    }
+    Ok(ranges)
+}
+
+/// Sends every range through one layer of maps, splitting it wherever it
+/// crosses the edge of a map. Pieces no map covers keep their values.
+pub fn map_ranges(ranges: &[Range<u64>], layer: &[AlmanacMap]) -> Vec<Range<u64>> {
+    let mut mapped = Vec::with_capacity(ranges.len());
+    let mut unmapped = ranges.to_vec();
+    for map in layer {
+        let mut outside = Vec::with_capacity(unmapped.len());
+        for range in unmapped {
+            let overlap = range.start.max(map.start.start)..range.end.min(map.start.end);
+            if overlap.is_empty() {
+                outside.push(range);
+                continue;
+            }
+            let destination = map.destination.start + (overlap.start - map.start.start);
+            mapped.push(destination..destination + (overlap.end - overlap.start));
+            if range.start < overlap.start {
+                outside.push(range.start..overlap.start);
+            }
+            if overlap.end < range.end {
+                outside.push(overlap.end..range.end);
+            }
+        }
+        unmapped = outside;
+    }
+    mapped.extend(unmapped);
+    mapped
 }
 
 #[cfg(test)]
@@ -11,9 +87,67 @@ mod tests {
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let input = include_str!("../test-input.txt");
+        assert_eq!(46, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_process_by_location() -> miette::Result<()> {
+        let input = include_str!("../test-input.txt");
+        assert_eq!(46, process_by_location(input)?);
+        let input = include_str!("../input2.txt");
+        assert_eq!(process(input)?, process_by_location(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_ranges() {
+        let layer = [AlmanacMap {
+            start: 10..20,
+            destination: 100..110,
+        }];
+        let mut split = map_ranges(&[5..25, 30..32], &layer);
+        split.sort_by_key(|range| range.start);
+        assert_eq!(vec![5..10, 20..25, 30..32, 100..110], split);
+        assert_eq!(vec![12..14, 40..41], map_ranges(&[12..14, 40..41], &[]));
+        assert_eq!(
+            vec![102..104, 40..41],
+            map_ranges(&[12..14, 40..41], &layer)
+        );
+    }
+
+    #[test]
+    fn test_matches_part1_on_single_seeds() -> miette::Result<()> {
+        // every seed as a range of length one gives part 1's answer
+        let input = include_str!("../input1.txt");
+        let almanac = parse_almanac(input)?;
+        let single_seeds = almanac
+            .seeds
+            .iter()
+            .map(|seed| format!("{seed} 1"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let (_, rest) = input.split_once('\n').unwrap();
+        let single_seed_input = format!("seeds: {single_seeds}\n{rest}");
+        assert_eq!(crate::part1::process(input)?, process(&single_seed_input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_range_overflow() {
+        assert!(matches!(
+            seed_ranges(&[u64::MAX - 1, 2]),
+            Err(AocError::RangeOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_unpaired_seed() {
+        assert!(matches!(
+            seed_ranges(&[79, 14, 55]),
+            Err(AocError::UnpairedSeed(55))
+        ));
+        assert_eq!(Vec::<Range<u64>>::new(), seed_ranges(&[]).unwrap());
+    }
 }
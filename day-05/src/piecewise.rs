@@ -0,0 +1,231 @@
+//! The whole map chain folded into one function, so a seed's location (or
+//! the seeds behind a location) is a single binary search away.
+//!
+//! The function is defined on `0..u64::MAX`; `u64::MAX` itself can't be the
+//! start of a range so is left out.
+use std::ops::Range;
+
+use crate::part1::{Almanac, CategoryMap};
+
+/// Values in `source` map in order onto the range starting at `destination_start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub source: Range<u64>,
+    pub destination_start: u64,
+}
+
+impl Segment {
+    pub fn destination(&self) -> Range<u64> {
+        self.destination_start..self.destination_start + (self.source.end - self.source.start)
+    }
+
+    fn apply(&self, value: u64) -> u64 {
+        self.destination_start + (value - self.source.start)
+    }
+}
+
+/// Segments sorted by source that cover the whole domain without overlapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piecewise {
+    segments: Vec<Segment>,
+}
+
+impl Default for Piecewise {
+    fn default() -> Self {
+        Piecewise::identity()
+    }
+}
+
+impl Piecewise {
+    pub fn identity() -> Self {
+        Piecewise {
+            segments: vec![Segment {
+                source: 0..u64::MAX,
+                destination_start: 0,
+            }],
+        }
+    }
+
+    /// The same function as [`CategoryMap::convert`], including which range
+    /// wins if two of them overlap.
+    pub fn from_map(map: &CategoryMap) -> Self {
+        let mut breakpoints: Vec<u64> = map
+            .ranges
+            .iter()
+            .flat_map(|m| [m.start.start, m.start.end])
+            .chain([0, u64::MAX])
+            .collect();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        let segments = breakpoints
+            .windows(2)
+            .map(|pair| Segment {
+                source: pair[0]..pair[1],
+                destination_start: map.convert(pair[0]),
+            })
+            .collect();
+        Piecewise { segments }.merged()
+    }
+
+    /// The seed to location function of the whole almanac.
+    pub fn from_almanac(almanac: &Almanac) -> Self {
+        almanac
+            .maps
+            .iter()
+            .fold(Piecewise::identity(), |composed, map| {
+                composed.then(&Piecewise::from_map(map))
+            })
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    pub fn apply(&self, value: u64) -> u64 {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.source.end <= value);
+        match self.segments.get(index) {
+            Some(segment) if segment.source.contains(&value) => segment.apply(value),
+            _ => value,
+        }
+    }
+
+    /// `next` applied after `self`.
+    pub fn then(&self, next: &Piecewise) -> Piecewise {
+        let mut segments = vec![];
+        for segment in &self.segments {
+            let image = segment.destination();
+            let first = next
+                .segments
+                .partition_point(|s| s.source.end <= image.start);
+            for next_segment in &next.segments[first..] {
+                if next_segment.source.start >= image.end {
+                    break;
+                }
+                let overlap = image.start.max(next_segment.source.start)
+                    ..image.end.min(next_segment.source.end);
+                let source_start = segment.source.start + (overlap.start - image.start);
+                segments.push(Segment {
+                    source: source_start..source_start + (overlap.end - overlap.start),
+                    destination_start: next_segment.apply(overlap.start),
+                });
+            }
+        }
+        Piecewise { segments }.merged()
+    }
+
+    /// Every source range that lands inside `target`, sorted and without overlaps.
+    pub fn inverse(&self, target: Range<u64>) -> Vec<Range<u64>> {
+        let mut ranges: Vec<Range<u64>> = self
+            .segments
+            .iter()
+            .filter_map(|segment| {
+                let destination = segment.destination();
+                let overlap = destination.start.max(target.start)..destination.end.min(target.end);
+                (!overlap.is_empty()).then(|| {
+                    let start = segment.source.start + (overlap.start - destination.start);
+                    start..start + (overlap.end - overlap.start)
+                })
+            })
+            .collect();
+        merge_ranges(&mut ranges);
+        ranges
+    }
+
+    /// The smallest value any input in `ranges` maps to. Segments are tried
+    /// from the lowest destination up, stopping once no later segment can beat
+    /// the best found.
+    pub fn min_over(&self, ranges: &[Range<u64>]) -> Option<u64> {
+        let mut by_destination: Vec<&Segment> = self.segments.iter().collect();
+        by_destination.sort_unstable_by_key(|segment| segment.destination_start);
+
+        let mut best: Option<u64> = None;
+        for segment in by_destination {
+            if best.is_some_and(|best| best <= segment.destination_start) {
+                break;
+            }
+            for range in ranges {
+                let start = range.start.max(segment.source.start);
+                if start < range.end.min(segment.source.end) {
+                    let value = segment.apply(start);
+                    best = Some(best.map_or(value, |best| best.min(value)));
+                }
+            }
+        }
+        best
+    }
+
+    /// Joins neighbouring segments that continue the same line.
+    fn merged(mut self) -> Self {
+        self.segments.dedup_by(|next, previous| {
+            let continues = previous.source.end == next.source.start
+                && previous.destination().end == next.destination_start;
+            if continues {
+                previous.source.end = next.source.end;
+            }
+            continues
+        });
+        self
+    }
+}
+
+fn merge_ranges(ranges: &mut Vec<Range<u64>>) {
+    ranges.sort_unstable_by_key(|range| range.start);
+    ranges.dedup_by(|next, previous| {
+        let touches = next.start <= previous.end;
+        if touches {
+            previous.end = previous.end.max(next.end);
+        }
+        touches
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::parse_almanac;
+
+    #[test]
+    fn test_matches_chain() -> miette::Result<()> {
+        for input in [
+            include_str!("../test-input.txt"),
+            include_str!("../input1.txt"),
+        ] {
+            let almanac = parse_almanac(input)?;
+            let composed = Piecewise::from_almanac(&almanac);
+            for &seed in &almanac.seeds {
+                assert_eq!(almanac.lookup(seed, "location")?, composed.apply(seed));
+            }
+            for window in composed.segments().windows(2) {
+                assert_eq!(window[0].source.end, window[1].source.start);
+            }
+            assert_eq!(0, composed.segments()[0].source.start);
+            assert_eq!(u64::MAX, composed.segments().last().unwrap().source.end);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse() -> miette::Result<()> {
+        let almanac = parse_almanac(include_str!("../test-input.txt"))?;
+        let composed = Piecewise::from_almanac(&almanac);
+
+        // seed 13 lands on 35 and seed 82 on 46
+        let below_36 = composed.inverse(0..36);
+        assert!(below_36.iter().any(|range| range.contains(&13)));
+        assert!(!below_36.iter().any(|range| range.contains(&82)));
+        for range in &below_36 {
+            for seed in [range.start, range.end - 1] {
+                assert!(composed.apply(seed) < 36);
+            }
+        }
+        // nothing outside those ranges does
+        for seed in 0..200 {
+            let inside = below_36.iter().any(|range| range.contains(&seed));
+            assert_eq!(inside, composed.apply(seed) < 36, "seed {seed}");
+        }
+        Ok(())
+    }
+}
@@ -19,4 +19,15 @@ fn part2() {
         "../input2.txt",
     )))
     .unwrap();
-}
\ No newline at end of file
+}
+#[cfg(feature = "no-alloc")]
+#[divan::bench]
+fn part1_no_alloc() {
+    no_alloc::part1(divan::black_box(include_bytes!("../input1.txt"))).unwrap();
+}
+
+#[cfg(feature = "no-alloc")]
+#[divan::bench]
+fn part2_no_alloc() {
+    no_alloc::part2(divan::black_box(include_bytes!("../input2.txt"))).unwrap();
+}
@@ -0,0 +1,22 @@
+use std::io;
+
+use day_07::stream;
+use miette::Context;
+
+/// Ranks the hands piped to stdin and prints the total winnings, with `J` as
+/// a jack (part 1) or a joker (part 2), e.g.
+/// `cargo run --release -p day-07 --bin stream -- 2 < input2.txt`
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let part = std::env::args().nth(1).unwrap_or_else(|| "1".to_string());
+    let stdin = io::stdin().lock();
+    let result = match part.as_str() {
+        "1" => stream::part1(stdin).context("stream part 1")?.to_string(),
+        "2" => stream::part2(stdin).context("stream part 2")?.to_string(),
+        _ => miette::bail!("unknown part `{part}`, expected 1 or 2"),
+    };
+    println!("{}", result);
+    Ok(())
+}
@@ -0,0 +1,167 @@
+//! Camel Cards without allocating: every hand is packed into one `u64` sort
+//! key in a stack buffer of [`MAX_HANDS`] keys, and sorting that buffer
+//! ranks the hands.
+use core::fmt;
+
+/// Why the hands couldn't be ranked. Offsets are bytes into the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoAllocError {
+    /// A card that isn't one of `23456789TJQKA`, or a hand that isn't five
+    /// cards long.
+    InvalidCard { offset: usize },
+    /// The bid after the hand is missing or isn't a number.
+    InvalidBid { offset: usize },
+    /// The input has more than [`MAX_HANDS`] hands.
+    TooManyHands,
+}
+
+impl fmt::Display for NoAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoAllocError::InvalidCard { offset } => write!(f, "invalid card at byte {offset}"),
+            NoAllocError::InvalidBid { offset } => write!(f, "invalid bid at byte {offset}"),
+            NoAllocError::TooManyHands => write!(f, "input has more than {MAX_HANDS} hands"),
+        }
+    }
+}
+
+pub const MAX_HANDS: usize = 2048;
+
+const JOKER: u8 = 1;
+
+pub fn part1(input: &[u8]) -> Result<u64, NoAllocError> {
+    total_winnings(input, false)
+}
+
+pub fn part2(input: &[u8]) -> Result<u64, NoAllocError> {
+    total_winnings(input, true)
+}
+
+fn total_winnings(input: &[u8], jokers: bool) -> Result<u64, NoAllocError> {
+    let mut hands = [0u64; MAX_HANDS];
+    let mut len = 0;
+
+    let mut offset = 0;
+    while offset < input.len() {
+        let line_end = input[offset..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map_or(input.len(), |end| offset + end);
+        let line = &input[offset..line_end];
+        if !line.is_empty() {
+            if len == MAX_HANDS {
+                return Err(NoAllocError::TooManyHands);
+            }
+            hands[len] = parse_hand(line, offset, jokers)?;
+            len += 1;
+        }
+        offset = line_end + 1;
+    }
+
+    let hands = &mut hands[..len];
+    hands.sort_unstable();
+    Ok(hands
+        .iter()
+        .enumerate()
+        .map(|(rank, hand)| (rank as u64 + 1) * u64::from(*hand as u32))
+        .sum())
+}
+
+/// Packs a hand as `kind | five card values | bid`, so sorting the packed
+/// values orders the hands by strength. `offset` is where `line` starts.
+fn parse_hand(line: &[u8], offset: usize, jokers: bool) -> Result<u64, NoAllocError> {
+    if line.len() < 6 || line[5] != b' ' {
+        return Err(NoAllocError::InvalidCard {
+            offset: offset + line.len().min(5),
+        });
+    }
+
+    let mut counts = [0u8; 15];
+    let mut key = 0u64;
+    for (i, card) in line[..5].iter().enumerate() {
+        let value = match card {
+            b'2'..=b'9' => card - b'0',
+            b'T' => 10,
+            b'J' if jokers => JOKER,
+            b'J' => 11,
+            b'Q' => 12,
+            b'K' => 13,
+            b'A' => 14,
+            _ => return Err(NoAllocError::InvalidCard { offset: offset + i }),
+        };
+        counts[value as usize] += 1;
+        key = key << 4 | u64::from(value);
+    }
+
+    let mut bid = None;
+    for (i, digit) in line[6..].iter().enumerate() {
+        match digit {
+            b'0'..=b'9' => {
+                bid = bid
+                    .unwrap_or(0u32)
+                    .checked_mul(10)
+                    .and_then(|bid| bid.checked_add(u32::from(digit - b'0')));
+                if bid.is_none() {
+                    return Err(NoAllocError::InvalidBid { offset: offset + 6 });
+                }
+            }
+            b'\r' => {}
+            _ => {
+                return Err(NoAllocError::InvalidBid {
+                    offset: offset + 6 + i,
+                })
+            }
+        }
+    }
+    let bid = bid.ok_or(NoAllocError::InvalidBid { offset: offset + 6 })?;
+
+    Ok(u64::from(kind(&mut counts)) << 52 | key << 32 | u64::from(bid))
+}
+
+/// Matches the ordering of `part1::Kind`, from high card (0) to five of a kind (6).
+fn kind(counts: &mut [u8; 15]) -> u8 {
+    let jokers = core::mem::take(&mut counts[JOKER as usize]);
+    let distinct = counts.iter().filter(|c| **c > 0).count();
+    let max = counts.iter().max().copied().unwrap_or(0) + jokers;
+
+    match (distinct, max) {
+        (0 | 1, _) => 6,
+        (2, 4) => 5,
+        (2, _) => 4,
+        (3, 3) => 3,
+        (3, _) => 2,
+        (4, _) => 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        for input in [
+            include_str!("../test-input.txt"),
+            include_str!("../input1.txt"),
+        ] {
+            assert_eq!(
+                crate::part1::process(input)?,
+                part1(input.as_bytes()).unwrap()
+            );
+            assert_eq!(
+                crate::part2::process(input)?,
+                part2(input.as_bytes()).unwrap()
+            );
+        }
+        assert_eq!(
+            Err(NoAllocError::InvalidCard { offset: 2 }),
+            part1(b"32X3K 765")
+        );
+        assert_eq!(
+            Err(NoAllocError::InvalidBid { offset: 16 }),
+            part1(b"32T3K 765\nT55J5 \n")
+        );
+        Ok(())
+    }
+}
@@ -10,15 +10,17 @@ use nom::{
 use crate::custom_error::AocError;
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32, AocError> {
+pub fn process(input: &str) -> miette::Result<u64, AocError> {
     let (_, mut hands) = parse_input(input).map_err(|e| AocError::NomParseError(e.to_string()))?;
 
-    hands.sort();
-    let score = hands.iter().enumerate().fold(0, |score, (rank, hand)| {
-        score + (((rank as u32) + 1) * hand.bid)
-    });
+    Ok(total_winnings(&mut hands))
+}
 
-    Ok(score)
+pub fn total_winnings(hands: &mut [Hand]) -> u64 {
+    hands.sort();
+    hands.iter().enumerate().fold(0, |score, (rank, hand)| {
+        score + ((rank as u64 + 1) * u64::from(hand.bid))
+    })
 }
 
 pub fn parse_input(input: &str) -> IResult<&str, Vec<Hand>> {
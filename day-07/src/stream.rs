@@ -0,0 +1,45 @@
+//! Camel Cards over any [`BufRead`]. Ranking needs every hand, so the parsed
+//! hands are kept, but never the input text.
+use std::io::BufRead;
+
+use line_stream::for_each_line;
+use nom::{combinator::all_consuming, IResult};
+
+use crate::{custom_error::AocError, part1, part2};
+
+pub fn part1<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    let mut hands = read_hands(reader, part1::hand)?;
+    Ok(part1::total_winnings(&mut hands))
+}
+
+pub fn part2<R: BufRead>(reader: R) -> miette::Result<u64, AocError> {
+    let mut hands = read_hands(reader, part2::hand)?;
+    Ok(part2::total_winnings(&mut hands))
+}
+
+fn read_hands<R: BufRead, H>(
+    reader: R,
+    hand: fn(&str) -> IResult<&str, H>,
+) -> miette::Result<Vec<H>, AocError> {
+    let mut hands = Vec::new();
+    for_each_line(reader, |line_number, line| {
+        let (_, hand) = all_consuming(hand)(line)
+            .map_err(|e| AocError::NomParseError(format!("line {line_number}: {e}")))?;
+        hands.push(hand);
+        Ok::<_, AocError>(())
+    })?;
+    Ok(hands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(crate::part1::process(input)?, part1(input.as_bytes())?);
+        assert_eq!(crate::part2::process(input)?, part2(input.as_bytes())?);
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
 pub mod custom_error;
 pub mod part1;
 pub mod part2;
+#[cfg(feature = "no-alloc")]
+pub mod no_alloc;
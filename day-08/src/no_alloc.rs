@@ -0,0 +1,203 @@
+//! Network walks without allocating. Node names are read as three base-36
+//! digits and the nodes are kept sorted by name in fixed arrays of
+//! [`MAX_NODES`], with left and right stored as indices into them.
+use core::fmt;
+
+/// Why the network couldn't be read or walked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoAllocError {
+    /// An instruction other than `L` or `R`, at this byte offset.
+    InvalidInstruction { offset: usize },
+    /// A line that isn't `AAA = (BBB, CCC)`, at this byte offset.
+    InvalidNode { offset: usize },
+    /// The network has more than [`MAX_NODES`] nodes.
+    TooManyNodes,
+    /// The walk started at or reached a node that has no line of its own.
+    MissingNode,
+}
+
+impl fmt::Display for NoAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoAllocError::InvalidInstruction { offset } => {
+                write!(f, "instruction at byte {offset} isn't L or R")
+            }
+            NoAllocError::InvalidNode { offset } => {
+                write!(f, "expected `AAA = (BBB, CCC)` at byte {offset}")
+            }
+            NoAllocError::TooManyNodes => write!(f, "network has more than {MAX_NODES} nodes"),
+            NoAllocError::MissingNode => write!(f, "walked to a node that isn't defined"),
+        }
+    }
+}
+
+pub const MAX_NODES: usize = 1024;
+const NO_NODE: u16 = u16::MAX;
+const START: u16 = 10 * 36 * 36 + 10 * 36 + 10; // AAA
+const END: u16 = 35 * 36 * 36 + 35 * 36 + 35; // ZZZ
+
+struct Network<'a> {
+    instructions: &'a [u8],
+    len: usize,
+    names: [u16; MAX_NODES],
+    left: [u16; MAX_NODES],
+    right: [u16; MAX_NODES],
+}
+
+pub fn part1(input: &[u8]) -> Result<u64, NoAllocError> {
+    let network = Network::parse(input)?;
+    let start = network.index(START).ok_or(NoAllocError::MissingNode)?;
+    network.steps(start, |name| name == END)
+}
+
+pub fn part2(input: &[u8]) -> Result<u64, NoAllocError> {
+    let network = Network::parse(input)?;
+    let mut lcm = 1;
+    for start in (0..network.len).filter(|i| network.names[*i] % 36 == 10) {
+        let steps = network.steps(start as u16, |name| name % 36 == 35)?;
+        lcm = lcm / gcd(lcm, steps) * steps;
+    }
+    Ok(lcm)
+}
+
+impl<'a> Network<'a> {
+    fn parse(input: &'a [u8]) -> Result<Self, NoAllocError> {
+        let instructions_end =
+            input
+                .iter()
+                .position(|b| *b == b'\n')
+                .ok_or(NoAllocError::InvalidInstruction {
+                    offset: input.len(),
+                })?;
+        let instructions = &input[..instructions_end];
+        if let Some(bad) = instructions.iter().position(|b| !matches!(b, b'L' | b'R')) {
+            return Err(NoAllocError::InvalidInstruction { offset: bad });
+        }
+
+        // each node line is `AAA = (BBB, CCC)`, packed as `AAA | BBB | CCC`
+        // so sorting orders the nodes by name
+        let mut nodes = [0u64; MAX_NODES];
+        let mut len = 0;
+        let mut offset = instructions_end + 1;
+        while offset < input.len() {
+            if matches!(input[offset], b'\n' | b'\r') {
+                offset += 1;
+                continue;
+            }
+            let invalid = |at| NoAllocError::InvalidNode {
+                offset: offset + at,
+            };
+            let line = input.get(offset..offset + 16).ok_or(invalid(0))?;
+            if &line[3..7] != b" = (" || &line[10..12] != b", " || line[15] != b')' {
+                return Err(invalid(0));
+            }
+            if len == MAX_NODES {
+                return Err(NoAllocError::TooManyNodes);
+            }
+            let node = node_id(&line[0..3]).ok_or(invalid(0))?;
+            let left = node_id(&line[7..10]).ok_or(invalid(7))?;
+            let right = node_id(&line[12..15]).ok_or(invalid(12))?;
+            nodes[len] = u64::from(node) << 32 | u64::from(left) << 16 | u64::from(right);
+            len += 1;
+            offset += 16;
+        }
+        let nodes = &mut nodes[..len];
+        nodes.sort_unstable();
+
+        let mut network = Network {
+            instructions,
+            len,
+            names: [NO_NODE; MAX_NODES],
+            left: [NO_NODE; MAX_NODES],
+            right: [NO_NODE; MAX_NODES],
+        };
+        for (i, node) in nodes.iter().enumerate() {
+            network.names[i] = (node >> 32) as u16;
+        }
+        for (i, node) in nodes.iter().enumerate() {
+            network.left[i] = network.index((node >> 16) as u16).unwrap_or(NO_NODE);
+            network.right[i] = network.index(*node as u16).unwrap_or(NO_NODE);
+        }
+        Ok(network)
+    }
+
+    fn index(&self, name: u16) -> Option<u16> {
+        self.names[..self.len]
+            .binary_search(&name)
+            .ok()
+            .map(|i| i as u16)
+    }
+
+    fn steps(&self, mut node: u16, is_end: impl Fn(u16) -> bool) -> Result<u64, NoAllocError> {
+        let mut steps = 0u64;
+        for instruction in self.instructions.iter().cycle() {
+            if is_end(self.names[node as usize]) {
+                break;
+            }
+            node = match instruction {
+                b'L' => self.left[node as usize],
+                _ => self.right[node as usize],
+            };
+            if node == NO_NODE {
+                return Err(NoAllocError::MissingNode);
+            }
+            steps += 1;
+        }
+        Ok(steps)
+    }
+}
+
+fn node_id(name: &[u8]) -> Option<u16> {
+    name.iter().try_fold(0u16, |id, b| {
+        let digit = match b {
+            b'0'..=b'9' => b - b'0',
+            b'A'..=b'Z' => b - b'A' + 10,
+            _ => return None,
+        };
+        Some(id * 36 + u16::from(digit))
+    })
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        for input in [
+            include_str!("../test-input1.txt"),
+            include_str!("../test-input2.txt"),
+            include_str!("../input1.txt"),
+        ] {
+            assert_eq!(
+                u64::from(crate::part1::process(input)?),
+                part1(input.as_bytes()).unwrap()
+            );
+        }
+        for input in [
+            include_str!("../test-input3.txt"),
+            include_str!("../input2.txt"),
+        ] {
+            assert_eq!(
+                crate::part2::process(input)?,
+                part2(input.as_bytes()).unwrap()
+            );
+        }
+        assert_eq!(
+            Err(NoAllocError::MissingNode),
+            part1(b"L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")
+        );
+        assert_eq!(
+            Err(NoAllocError::InvalidNode { offset: 10 }),
+            part1(b"L\n\nAAA = (b01, ZZZ)\n")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use std::io;
+
+use day_09::stream;
+use miette::Context;
+
+/// Sums the next (part 1) or previous (part 2) value of every history piped
+/// to stdin, e.g.
+/// `cargo run --release -p day-09 --bin stream -- 1 < input1.txt`
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let part = std::env::args().nth(1).unwrap_or_else(|| "1".to_string());
+    let stdin = io::stdin().lock();
+    let result = match part.as_str() {
+        "1" => stream::part1(stdin).context("stream part 1")?.to_string(),
+        "2" => stream::part2(stdin).context("stream part 2")?.to_string(),
+        _ => miette::bail!("unknown part `{part}`, expected 1 or 2"),
+    };
+    println!("{}", result);
+    Ok(())
+}
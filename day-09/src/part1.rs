@@ -11,26 +11,24 @@ pub fn process(input: &str) -> miette::Result<i64, AocError> {
     let (_, values) = separated_list1(newline, parse_line)(input)
         .map_err(|e| AocError::NomParseError(e.to_string()))?;
 
-    let next_value_sum = values
-        .into_iter()
-        .map(|value_list| {
-            let mut stack = vec![*value_list.last().unwrap()];
-            let mut current_stack = value_list;
-            while !current_stack.iter().all(|v| *v == 0) {
-                current_stack = current_stack
-                    .iter()
-                    .tuple_windows()
-                    .map(|(l, r)| r - l)
-                    .collect();
-                stack.push(*current_stack.last().unwrap());
-            }
-            let last_number = stack.iter().fold(0, |number_to_add, v| number_to_add + *v);
-            last_number
-        })
-        .sum();
+    let next_value_sum = values.into_iter().map(next_value).sum();
     Ok(next_value_sum)
 }
 
+pub fn next_value(value_list: Vec<i64>) -> i64 {
+    let mut stack = vec![*value_list.last().unwrap()];
+    let mut current_stack = value_list;
+    while !current_stack.iter().all(|v| *v == 0) {
+        current_stack = current_stack
+            .iter()
+            .tuple_windows()
+            .map(|(l, r)| r - l)
+            .collect();
+        stack.push(*current_stack.last().unwrap());
+    }
+    stack.iter().fold(0, |number_to_add, v| number_to_add + *v)
+}
+
 pub fn parse_line(input: &str) -> nom::IResult<&str, Vec<i64>> {
     separated_list1(space1, nom::character::complete::i64)(input)
 }
@@ -0,0 +1,43 @@
+//! Extrapolation sums over any [`BufRead`], one history per line, so only
+//! the history being extrapolated is held in memory.
+use std::io::BufRead;
+
+use line_stream::for_each_line;
+use nom::combinator::all_consuming;
+
+use crate::{custom_error::AocError, part1, part2};
+
+pub fn part1<R: BufRead>(reader: R) -> miette::Result<i64, AocError> {
+    sum_lines(reader, part1::next_value)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> miette::Result<i64, AocError> {
+    sum_lines(reader, part2::previous_value)
+}
+
+fn sum_lines<R: BufRead>(
+    reader: R,
+    extrapolate: fn(Vec<i64>) -> i64,
+) -> miette::Result<i64, AocError> {
+    let mut sum = 0;
+    for_each_line(reader, |line_number, line| {
+        let (_, values) = all_consuming(part1::parse_line)(line)
+            .map_err(|e| AocError::NomParseError(format!("line {line_number}: {e}")))?;
+        sum += extrapolate(values);
+        Ok::<_, AocError>(())
+    })?;
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_process() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(crate::part1::process(input)?, part1(input.as_bytes())?);
+        assert_eq!(crate::part2::process(input)?, part2(input.as_bytes())?);
+        Ok(())
+    }
+}
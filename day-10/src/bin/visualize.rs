@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use day_10::{
+    custom_error::AocError,
+    part1::{distance_map, parse_input},
+    visualize::{loop_animation, loop_frame},
+};
+use miette::Context;
+
+/// Prints the traced loop, optionally animating it in the terminal
+/// (`--animate`) or writing it to a GIF (`--gif <path>`, needs the `gif` feature).
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut input_path = None;
+    let mut animate = false;
+    let mut gif_path: Option<String> = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--animate" => animate = true,
+            "--gif" => gif_path = args.next(),
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path).map_err(AocError::from)?,
+        None => include_str!("../../input1.txt").to_string(),
+    };
+    let (_, pipe_map) = parse_input(&input).map_err(|e| AocError::NomParseError(e.to_string()))?;
+    let distances = distance_map(&pipe_map);
+    let animation = loop_animation(&pipe_map, &distances, 60, Duration::from_millis(50));
+
+    if animate {
+        animation
+            .play(&mut std::io::stdout())
+            .context("play animation")?;
+    } else {
+        print!(
+            "{}",
+            loop_frame(&pipe_map, &distances, u32::MAX).render_ansi()
+        );
+    }
+
+    if let Some(path) = gif_path {
+        #[cfg(feature = "gif")]
+        animation.write_gif(path, 4).context("write gif")?;
+        #[cfg(not(feature = "gif"))]
+        tracing::warn!("not writing {path}: rebuild with `--features gif`");
+    }
+    Ok(())
+}
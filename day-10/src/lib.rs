@@ -1,3 +1,4 @@
 pub mod custom_error;
 pub mod part1;
 pub mod part2;
+pub mod visualize;
@@ -13,6 +13,20 @@ use crate::custom_error::AocError;
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
     let (_, pipe_map) = parse_input(input).map_err(|e| AocError::NomParseError(e.to_string()))?;
+    let distance_map = distance_map(&pipe_map);
+
+    let max_distance = distance_map
+        .iter()
+        .map(|s| s.unwrap_or(0))
+        .max()
+        .expect("No max value");
+
+    Ok(max_distance)
+}
+
+/// Walks out from the start in every direction, keeping the shortest
+/// distance to each pipe reached. Cells never reached are `None`.
+pub fn distance_map(pipe_map: &Array2<Pipe>) -> Array2<Option<u32>> {
     let ((start_row, start_col), _) = pipe_map
         .indexed_iter()
         .find(|((_, _), p)| **p == Pipe::Start)
@@ -75,13 +89,7 @@ pub fn process(input: &str) -> miette::Result<u32, AocError> {
         }
     }
 
-    let max_distance = distance_map
-        .iter()
-        .map(|s| s.unwrap_or(0))
-        .max()
-        .expect("No max value");
-
-    Ok(max_distance)
+    distance_map
 }
 
 pub fn parse_input(input: &str) -> IResult<&str, Array2<Pipe>> {
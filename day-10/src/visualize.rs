@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use grid_viz::{Animation, Cell, Color, Frame, Style};
+use ndarray::Array2;
+
+use crate::part1::Pipe;
+
+fn glyph(pipe: &Pipe) -> char {
+    match pipe {
+        Pipe::Start => 'S',
+        Pipe::NoPipe => '.',
+        Pipe::Vertical => '│',
+        Pipe::Horizontal => '─',
+        Pipe::NorthEastBend => '└',
+        Pipe::NorthWestBend => '┘',
+        Pipe::SouthWestBend => '┐',
+        Pipe::SouthEastBend => '┌',
+    }
+}
+
+/// Renders the pipe map with the traced loop coloured by distance from the
+/// start. Only cells at most `up_to` steps away are coloured.
+pub fn loop_frame(
+    pipe_map: &Array2<Pipe>,
+    distance_map: &Array2<Option<u32>>,
+    up_to: u32,
+) -> Frame {
+    let max_distance = distance_map
+        .iter()
+        .flatten()
+        .max()
+        .copied()
+        .unwrap_or(0)
+        .max(1);
+
+    Frame::from_array(pipe_map, |index, pipe| match (pipe, distance_map[index]) {
+        (Pipe::Start, _) => Cell::styled('S', Style::default().bg(Color::MAGENTA).bold()),
+        // the walk counts its first step from the start even onto ground
+        (Pipe::NoPipe, _) => Cell::styled('.', Style::default().fg(Color::GREY)),
+        (_, Some(distance)) if distance <= up_to => {
+            let heat = Color::heat(distance as f32 / max_distance as f32);
+            Cell::styled(glyph(pipe), Style::default().fg(heat).bold())
+        }
+        _ => Cell::styled(glyph(pipe), Style::default().fg(Color::GREY)),
+    })
+}
+
+/// One frame per step along the loop, capped at roughly `max_frames` frames.
+pub fn loop_animation(
+    pipe_map: &Array2<Pipe>,
+    distance_map: &Array2<Option<u32>>,
+    max_frames: u32,
+    delay: Duration,
+) -> Animation {
+    let max_distance = distance_map.iter().flatten().max().copied().unwrap_or(0);
+    let step = (max_distance / max_frames.max(1)).max(1);
+
+    let mut animation = Animation::new(delay);
+    for up_to in (0..=max_distance).step_by(step as usize) {
+        animation.push(loop_frame(pipe_map, distance_map, up_to));
+    }
+    animation.push(loop_frame(pipe_map, distance_map, max_distance));
+    animation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::{distance_map, parse_input};
+
+    #[test]
+    fn test_loop_frame() -> miette::Result<()> {
+        let (_, pipe_map) = parse_input(include_str!("../test-input2.txt")).unwrap();
+        let distances = distance_map(&pipe_map);
+        let frame = loop_frame(&pipe_map, &distances, u32::MAX);
+        assert_eq!("┐─┌┐─\n.┌┘│┐\nS┘└└┐\n│┌──┘\n└┘.└┘\n", frame.to_string());
+        let loop_cells = frame
+            .cells()
+            .iter()
+            .filter(|c| c.style.bold && c.glyph != 'S')
+            .count();
+        assert_eq!(15, loop_cells);
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use day_11::{
+    custom_error::AocError,
+    part1::parse_input,
+    visualize::{expand_map, expanded_galaxies, galaxy_frame, pair_animation},
+};
+use miette::Context;
+
+/// Prints the expanded galaxy map. `--pair <a> <b>` traces the path between
+/// two galaxies (numbered from 1), `--animate` steps through the pairs in the
+/// terminal and `--gif <path>` writes them to a GIF (needs the `gif` feature).
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut input_path = None;
+    let mut pair = None;
+    let mut animate = false;
+    let mut gif_path: Option<String> = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pair" => {
+                let mut galaxy = || args.next().and_then(|n| n.parse::<usize>().ok());
+                pair = galaxy().zip(galaxy());
+            }
+            "--animate" => animate = true,
+            "--gif" => gif_path = args.next(),
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let input = match input_path {
+        Some(path) => std::fs::read_to_string(path).map_err(AocError::from)?,
+        None => include_str!("../../input1.txt").to_string(),
+    };
+    let (_, galaxy_map) =
+        parse_input(&input).map_err(|e| AocError::NomParseError(e.to_string()))?;
+    let expanded_map = expand_map(&galaxy_map);
+    let galaxies = expanded_galaxies(&galaxy_map, 2);
+    let animation = pair_animation(&expanded_map, &galaxies, 100, Duration::from_millis(100));
+
+    if animate {
+        animation
+            .play(&mut std::io::stdout())
+            .context("play animation")?;
+    } else {
+        let pair = pair.and_then(|(a, b)| {
+            Some((
+                *galaxies.get(a.checked_sub(1)?)?,
+                *galaxies.get(b.checked_sub(1)?)?,
+            ))
+        });
+        print!("{}", galaxy_frame(&expanded_map, pair).render_ansi());
+    }
+
+    if let Some(path) = gif_path {
+        #[cfg(feature = "gif")]
+        animation.write_gif(path, 4).context("write gif")?;
+        #[cfg(not(feature = "gif"))]
+        tracing::warn!("not writing {path}: rebuild with `--features gif`");
+    }
+    Ok(())
+}
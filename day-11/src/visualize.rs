@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use grid_viz::{Animation, Cell, Color, Frame, Style};
+use ndarray::Array2;
+
+/// Positions of every galaxy after each empty row and column has been
+/// replaced by `expansion` of them. An `expansion` of 0 is treated as 1.
+pub fn expanded_galaxies(galaxy_map: &Array2<u8>, expansion: usize) -> Vec<(usize, usize)> {
+    let empty_rows: Vec<_> = galaxy_map
+        .rows()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, row)| row.sum() == 0)
+        .map(|(row_num, _)| row_num)
+        .collect();
+    let empty_cols: Vec<_> = galaxy_map
+        .columns()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, col)| col.sum() == 0)
+        .map(|(col_num, _)| col_num)
+        .collect();
+
+    let extra = expansion.saturating_sub(1);
+    galaxy_map
+        .indexed_iter()
+        .filter(|(_, val)| **val == 1)
+        .map(|((row, col), _)| {
+            let new_row = row + extra * empty_rows.iter().filter(|r| **r < row).count();
+            let new_col = col + extra * empty_cols.iter().filter(|c| **c < col).count();
+            (new_row, new_col)
+        })
+        .collect()
+}
+
+/// The galaxy map with every empty row and column doubled, galaxies as `1`.
+pub fn expand_map(galaxy_map: &Array2<u8>) -> Array2<u8> {
+    let galaxies = expanded_galaxies(galaxy_map, 2);
+    let empty_rows = galaxy_map
+        .rows()
+        .into_iter()
+        .filter(|r| r.sum() == 0)
+        .count();
+    let empty_cols = galaxy_map
+        .columns()
+        .into_iter()
+        .filter(|c| c.sum() == 0)
+        .count();
+
+    let mut expanded_map = Array2::zeros((
+        galaxy_map.nrows() + empty_rows,
+        galaxy_map.ncols() + empty_cols,
+    ));
+    for galaxy in galaxies {
+        expanded_map[galaxy] = 1;
+    }
+    expanded_map
+}
+
+pub fn galaxy_pairs(
+    galaxies: &[(usize, usize)],
+) -> impl Iterator<Item = ((usize, usize), (usize, usize))> + '_ {
+    galaxies
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, a)| galaxies[i + 1..].iter().map(move |b| (*a, *b)))
+}
+
+pub fn distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+/// Renders the expanded map, optionally tracing the shortest path between a
+/// pair of galaxies (down/up first, then across).
+pub fn galaxy_frame(
+    expanded_map: &Array2<u8>,
+    pair: Option<((usize, usize), (usize, usize))>,
+) -> Frame {
+    let mut frame = Frame::from_array(expanded_map, |_, val| {
+        if *val == 1 {
+            Cell::styled('#', Style::default().fg(Color::YELLOW).bold())
+        } else {
+            Cell::styled('.', Style::default().fg(Color::GREY))
+        }
+    });
+
+    if let Some((from, to)) = pair {
+        for (row, col) in path(from, to) {
+            frame.highlight(row, col, Color::BLUE);
+        }
+        frame.highlight(from.0, from.1, Color::GREEN);
+        frame.highlight(to.0, to.1, Color::RED);
+    }
+    frame
+}
+
+fn path(from: (usize, usize), to: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    let rows = from.0.min(to.0)..=from.0.max(to.0);
+    let cols = from.1.min(to.1)..=from.1.max(to.1);
+    rows.map(move |row| (row, from.1))
+        .chain(cols.map(move |col| (to.0, col)))
+}
+
+/// One frame per galaxy pair, stopping after `max_frames` pairs.
+pub fn pair_animation(
+    expanded_map: &Array2<u8>,
+    galaxies: &[(usize, usize)],
+    max_frames: usize,
+    delay: Duration,
+) -> Animation {
+    let mut animation = Animation::new(delay);
+    for pair in galaxy_pairs(galaxies).take(max_frames) {
+        animation.push(galaxy_frame(expanded_map, Some(pair)));
+    }
+    animation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::parse_input;
+
+    #[test]
+    fn test_galaxy_frame() -> miette::Result<()> {
+        let (_, galaxy_map) = parse_input(include_str!("../test-input.txt")).unwrap();
+        let expanded_map = expand_map(&galaxy_map);
+        let galaxies = expanded_galaxies(&galaxy_map, 2);
+        assert_eq!((12, 13), expanded_map.dim());
+        assert_eq!(
+            expanded_galaxies(&galaxy_map, 1),
+            expanded_galaxies(&galaxy_map, 0)
+        );
+
+        // galaxy 5 to galaxy 9 from the puzzle description
+        let pair = (galaxies[4], galaxies[8]);
+        let frame = galaxy_frame(&expanded_map, Some(pair));
+        let highlighted = frame
+            .cells()
+            .iter()
+            .filter(|c| c.style.bg.is_some())
+            .count();
+        assert_eq!(9, distance(pair.0, pair.1));
+        assert_eq!(10, highlighted);
+        assert_eq!(
+            "....#........\n",
+            frame
+                .to_string()
+                .lines()
+                .next()
+                .map(|l| format!("{l}\n"))
+                .unwrap()
+        );
+        Ok(())
+    }
+}
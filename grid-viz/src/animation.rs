@@ -0,0 +1,116 @@
+use std::{io::Write, thread, time::Duration};
+
+use crate::{custom_error::VizError, Frame};
+
+/// A sequence of frames shown with a fixed delay between them.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    delay: Duration,
+}
+
+impl Animation {
+    pub fn new(delay: Duration) -> Self {
+        Animation {
+            frames: Vec::new(),
+            delay,
+        }
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Plays the animation in place on a terminal.
+    pub fn play(&self, out: &mut impl Write) -> Result<(), VizError> {
+        if self.frames.is_empty() {
+            return Err(VizError::EmptyAnimation);
+        }
+        for frame in &self.frames {
+            write!(out, "\x1b[H\x1b[2J{}", frame.render_ansi())?;
+            out.flush()?;
+            thread::sleep(self.delay);
+        }
+        Ok(())
+    }
+
+    /// Writes the animation as a looping GIF, drawing each cell as a
+    /// `cell_size` pixel square in its background (or foreground) colour.
+    #[cfg(feature = "gif")]
+    pub fn write_gif(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        cell_size: u16,
+    ) -> Result<(), VizError> {
+        let first = self.frames.first().ok_or(VizError::EmptyAnimation)?;
+        let pixels = |cells: usize| {
+            u16::try_from(cells)
+                .ok()
+                .and_then(|cells| cells.checked_mul(cell_size))
+                .ok_or(VizError::TooLarge {
+                    rows: first.nrows(),
+                    cols: first.ncols(),
+                    cell_size,
+                })
+        };
+        let width = pixels(first.ncols())?;
+        let height = pixels(first.nrows())?;
+
+        let file = std::fs::File::create(path)?;
+        let mut encoder = gif::Encoder::new(file, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        for frame in &self.frames {
+            let pixels = frame_pixels(frame, cell_size);
+            let mut gif_frame = gif::Frame::from_rgb_speed(width, height, &pixels, 10);
+            gif_frame.delay = (self.delay.as_millis() / 10) as u16;
+            encoder.write_frame(&gif_frame)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "gif")]
+fn frame_pixels(frame: &Frame, cell_size: u16) -> Vec<u8> {
+    use crate::Color;
+
+    let cell_size = cell_size as usize;
+    let width = frame.ncols() * cell_size;
+    let mut pixels = vec![0u8; width * frame.nrows() * cell_size * 3];
+    for ((row, col), cell) in frame.cells().indexed_iter() {
+        let Color(r, g, b) = match (cell.style.bg, cell.style.fg) {
+            (Some(bg), _) => bg,
+            (None, Some(fg)) if cell.glyph != ' ' => fg,
+            (None, None) if !matches!(cell.glyph, ' ' | '.') => Color::WHITE,
+            _ => Color::BLACK,
+        };
+        for y in row * cell_size..(row + 1) * cell_size {
+            for x in col * cell_size..(col + 1) * cell_size {
+                let i = (y * width + x) * 3;
+                pixels[i..i + 3].copy_from_slice(&[r, g, b]);
+            }
+        }
+    }
+    pixels
+}
+
+#[cfg(all(test, feature = "gif"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gif_too_large() {
+        let mut animation = Animation::new(Duration::from_millis(10));
+        animation.push(Frame::new(2, 20_000));
+        let path = std::env::temp_dir().join("grid-viz-too-large.gif");
+        assert!(matches!(
+            animation.write_gif(&path, 4),
+            Err(VizError::TooLarge { cols: 20_000, .. })
+        ));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,26 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum VizError {
+    #[error(transparent)]
+    #[diagnostic(code(grid_viz::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error("Animation has no frames")]
+    #[diagnostic(code(grid_viz::empty_animation))]
+    EmptyAnimation,
+
+    #[error("{rows}x{cols} cells of {cell_size}px don't fit in a GIF")]
+    #[diagnostic(code(grid_viz::too_large))]
+    TooLarge {
+        rows: usize,
+        cols: usize,
+        cell_size: u16,
+    },
+
+    #[cfg(feature = "gif")]
+    #[error(transparent)]
+    #[diagnostic(code(grid_viz::gif_error))]
+    GifError(#[from] gif::EncodingError),
+}
@@ -0,0 +1,198 @@
+use std::fmt::{Display, Write};
+
+use ndarray::Array2;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const GREY: Color = Color(110, 110, 110);
+    pub const WHITE: Color = Color(230, 230, 230);
+    pub const RED: Color = Color(220, 50, 47);
+    pub const GREEN: Color = Color(80, 200, 80);
+    pub const YELLOW: Color = Color(240, 200, 40);
+    pub const BLUE: Color = Color(60, 120, 220);
+    pub const MAGENTA: Color = Color(200, 80, 200);
+
+    /// Blue to red gradient for `t` in `0.0..=1.0`, useful for distance maps.
+    pub fn heat(t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t) as u8;
+        Color(channel(60, 230), channel(120, 60), channel(230, 40))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    fn write_ansi(&self, out: &mut String) {
+        out.push_str("\x1b[0");
+        if self.bold {
+            out.push_str(";1");
+        }
+        if let Some(Color(r, g, b)) = self.fg {
+            let _ = write!(out, ";38;2;{r};{g};{b}");
+        }
+        if let Some(Color(r, g, b)) = self.bg {
+            let _ = write!(out, ";48;2;{r};{g};{b}");
+        }
+        out.push('m');
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cell {
+    pub glyph: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new(glyph: char) -> Self {
+        Cell {
+            glyph,
+            style: Style::default(),
+        }
+    }
+
+    pub fn styled(glyph: char, style: Style) -> Self {
+        Cell { glyph, style }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell::new(' ')
+    }
+}
+
+/// A grid of styled characters that can be printed to a terminal or turned
+/// into an animation frame.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    cells: Array2<Cell>,
+}
+
+impl Frame {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Frame {
+            cells: Array2::default((rows, cols)),
+        }
+    }
+
+    pub fn from_array<T>(grid: &Array2<T>, to_cell: impl Fn((usize, usize), &T) -> Cell) -> Self {
+        let cells = Array2::from_shape_fn(grid.raw_dim(), |index| to_cell(index, &grid[index]));
+        Frame { cells }
+    }
+
+    /// Builds a frame from the rows of a puzzle input, one cell per character.
+    pub fn from_text(input: &str) -> Self {
+        let rows = input.lines().count();
+        let cols = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut frame = Frame::new(rows, cols);
+        for (row, line) in input.lines().enumerate() {
+            for (col, glyph) in line.chars().enumerate() {
+                frame.cells[(row, col)].glyph = glyph;
+            }
+        }
+        frame
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn cells(&self) -> &Array2<Cell> {
+        &self.cells
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&Cell> {
+        self.cells.get((row, col))
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut Cell> {
+        self.cells.get_mut((row, col))
+    }
+
+    /// Sets a cell, ignoring positions outside of the frame.
+    pub fn set(&mut self, row: usize, col: usize, cell: Cell) {
+        if let Some(c) = self.cells.get_mut((row, col)) {
+            *c = cell;
+        }
+    }
+
+    pub fn highlight(&mut self, row: usize, col: usize, color: Color) {
+        if let Some(c) = self.cells.get_mut((row, col)) {
+            c.style.bg = Some(color);
+        }
+    }
+
+    /// Renders the frame with 24-bit ANSI colour escapes.
+    pub fn render_ansi(&self) -> String {
+        let mut out = String::with_capacity(self.cells.len() * 4);
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if current != Some(cell.style) {
+                    cell.style.write_ansi(&mut out);
+                    current = Some(cell.style);
+                }
+                out.push(cell.glyph);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.rows() {
+            for cell in row {
+                f.write_char(cell.glyph)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut frame = Frame::from_text("#.\n.#");
+        frame.highlight(0, 0, Color::RED);
+        assert_eq!("#.\n.#\n", frame.to_string());
+        assert_eq!(
+            "\x1b[0;48;2;220;50;47m#\x1b[0m.\x1b[0m\n\x1b[0m.#\x1b[0m\n",
+            frame.render_ansi()
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod animation;
+pub mod custom_error;
+pub mod frame;
+
+pub use animation::Animation;
+pub use frame::{Cell, Color, Frame, Style};
@@ -0,0 +1,49 @@
+//! The line loop shared by the days' `stream` modules.
+use std::io::{self, BufRead};
+
+/// Calls `on_line` with the line number (from 1) and text of every non-empty
+/// line, without its `\n` or `\r\n`. A single buffer is reused, so memory is
+/// bounded by the longest line rather than the input.
+pub fn for_each_line<R, E>(
+    mut reader: R,
+    mut on_line: impl FnMut(usize, &str) -> Result<(), E>,
+) -> Result<(), E>
+where
+    R: BufRead,
+    E: From<io::Error>,
+{
+    let mut line = String::new();
+    let mut line_number = 0;
+    while reader.read_line(&mut line)? > 0 {
+        line_number += 1;
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        if !trimmed.is_empty() {
+            on_line(line_number, trimmed)?;
+        }
+        line.clear();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_line() -> io::Result<()> {
+        let mut lines = vec![];
+        for_each_line("one\r\n\ntwo\nthree".as_bytes(), |number, line| {
+            lines.push((number, line.to_string()));
+            io::Result::Ok(())
+        })?;
+        assert_eq!(
+            vec![
+                (1, "one".to_string()),
+                (3, "two".to_string()),
+                (4, "three".to_string())
+            ],
+            lines
+        );
+        Ok(())
+    }
+}
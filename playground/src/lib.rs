@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use wasm_bindgen::prelude::*;
+
+fn answer<T: Display, E: Display>(result: Result<T, E>) -> Result<String, String> {
+    result.map(|v| v.to_string()).map_err(|e| e.to_string())
+}
+
+/// Runs the solver for `day` and `part` against `input`.
+///
+/// Parts that are still `todo!()` return an error instead of being called,
+/// since a panic aborts the whole wasm module.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    match (day, part) {
+        (1, 1) => answer(day_01::part1::process(input)),
+        (1, 2) => answer(day_01::part2::process(input)),
+        (2, 1) => answer(day_02::part1::process(input)),
+        (2, 2) => answer(day_02::part2::process(input)),
+        (3, 1) => answer(day_03::part1::process(input)),
+        (3, 2) => answer(day_03::part2::process(input)),
+        (4, 1) => answer(day_04::part1::process(input)),
+        (4, 2) => answer(day_04::part2::process(input)),
+        (5, 1) => answer(day_05::part1::process(input)),
+        (5, 2) => answer(day_05::part2::process(input)),
+        (6, 1) => answer(day_06::part1::process(input)),
+        (6, 2) => answer(day_06::part2::process(input)),
+        (7, 1) => answer(day_07::part1::process(input)),
+        (7, 2) => answer(day_07::part2::process(input)),
+        (8, 1) => answer(day_08::part1::process(input)),
+        (8, 2) => answer(day_08::part2::process(input)),
+        (9, 1) => answer(day_09::part1::process(input)),
+        (9, 2) => answer(day_09::part2::process(input)),
+        (10, 1) => answer(day_10::part1::process(input)),
+        (1..=11, 1..=2) => Err(format!("day {day} part {part} is not solved yet")),
+        _ => Err(format!("there is no day {day} part {part}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            Ok("142".to_string()),
+            solve(1, 1, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet")
+        );
+        assert_eq!(
+            Ok("288".to_string()),
+            solve(6, 1, include_str!("../../day-06/test-input.txt"))
+        );
+        assert_eq!(
+            Err("day 10 part 2 is not solved yet".to_string()),
+            solve(10, 2, "")
+        );
+        assert_eq!(
+            Err("there is no day 12 part 1".to_string()),
+            solve(12, 1, "")
+        );
+    }
+}
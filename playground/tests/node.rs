@@ -0,0 +1,35 @@
+//! Runs under node with `wasm-bindgen-test-runner`:
+//! `cargo test -p playground --target wasm32-unknown-unknown`
+#![cfg(target_arch = "wasm32")]
+
+use playground::solve;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn solves_example_inputs() {
+    assert_eq!(
+        Ok("6440".to_string()),
+        solve(7, 1, include_str!("../../day-07/test-input.txt"))
+    );
+    assert_eq!(
+        Ok("114".to_string()),
+        solve(9, 1, include_str!("../../day-09/test-input.txt"))
+    );
+    assert_eq!(
+        Ok("46".to_string()),
+        solve(5, 2, include_str!("../../day-05/test-input.txt"))
+    );
+    assert_eq!(
+        Ok("8".to_string()),
+        solve(10, 1, include_str!("../../day-10/test-input2.txt"))
+    );
+}
+
+#[wasm_bindgen_test]
+fn reports_unsolved_parts() {
+    assert_eq!(
+        Err("day 10 part 2 is not solved yet".to_string()),
+        solve(10, 2, include_str!("../../day-10/test-input1.txt"))
+    );
+    assert!(solve(0, 1, "").is_err());
+}
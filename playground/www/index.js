@@ -0,0 +1,26 @@
+// Built with `just wasm-build`, which writes the bindings to ./pkg
+import init, { solve } from "./pkg/playground.js";
+
+const form = document.getElementById("solver");
+const answer = document.getElementById("answer");
+
+await init();
+form.querySelector("button").disabled = false;
+
+form.addEventListener("submit", (event) => {
+  event.preventDefault();
+  const day = Number(document.getElementById("day").value);
+  const part = Number(document.getElementById("part").value);
+  const input = document.getElementById("input").value.trimEnd();
+
+  const start = performance.now();
+  try {
+    const result = solve(day, part, input);
+    const elapsed = (performance.now() - start).toFixed(2);
+    answer.className = "";
+    answer.textContent = `${result} (${elapsed} ms)`;
+  } catch (error) {
+    answer.className = "error";
+    answer.textContent = String(error);
+  }
+});